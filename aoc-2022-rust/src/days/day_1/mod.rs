@@ -1,17 +1,17 @@
-use std::fs;
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
 
 #[derive(Clone)]
-struct Elf {
+pub struct Elf {
     food: u32,
 }
 
-fn parse_input(input_path: &str) -> Vec<Elf> {
-    let contents = fs::read_to_string(input_path).expect("Should have been able to read the file");
+fn parse_input(contents: &str) -> Vec<Elf> {
     let raw_input: Vec<&str> = contents.split('\n').collect();
 
     let mut elves: Vec<Elf> = Vec::new();
     let mut current_elf: Elf = Elf { food: 0 };
-    let mut largest_total_food: u32 = 0;
 
     for s in raw_input {
         let char_amount = s.len();
@@ -20,42 +20,61 @@ fn parse_input(input_path: &str) -> Vec<Elf> {
             current_elf.food += food_amount;
         } else {
             elves.push(current_elf.clone());
-            if current_elf.food > largest_total_food {
-                largest_total_food = current_elf.food;
-            }
             current_elf = Elf { food: 0 };
         }
     }
-    println!("Largest carried total food: {}", largest_total_food);
+    if current_elf.food > 0 {
+        elves.push(current_elf);
+    }
     elves
 }
 
-fn calculate_top_tree_total(elves: &mut Vec<Elf>) {
+fn largest_total_food(elves: &[Elf]) -> u32 {
+    elves.iter().map(|elf| elf.food).max().unwrap_or(0)
+}
+
+fn calculate_top_tree_total(elves: &mut Vec<Elf>) -> u32 {
     elves.sort_by(|a, b| b.food.cmp(&a.food));
 
     let top_tree = &elves[0..3];
-    let total = top_tree
+    top_tree
         .iter()
-        .fold(0, |total, value| total + value.food);
-
-    println!("total food of top tree: {}", total);
+        .fold(0, |total, value| total + value.food)
 }
 
-#[allow(dead_code)]
-fn main() {
-    let input_example_path = "src/bin/day1/input.example";
-    let input_path = "src/bin/day1/input";
-
-    // input_path.len()
-    println!("Part 1 - example input");
-    let mut elves_example = parse_input(input_example_path);
+pub struct Day1;
+impl Solution for Day1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
 
-    println!("Part 1 - input");
-    let mut elves = parse_input(input_path);
+    type Input<'a> = Vec<Elf>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    println!("Part 2 - example input");
-    calculate_top_tree_total(&mut elves_example);
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        parse_input(input)
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        largest_total_food(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        calculate_top_tree_total(&mut input.clone())
+    }
+}
 
-    println!("Part 2 - input");
-    calculate_top_tree_total(&mut elves);
+#[test]
+fn day_1_part_1_example() {
+    assert_answer(&Day1, &Variant::Example, Part::One, include_str!("input.example"));
+}
+#[test]
+fn day_1_part_1() {
+    assert_answer(&Day1, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_1_part_2_example() {
+    assert_answer(&Day1, &Variant::Example, Part::Two, include_str!("input.example"));
+}
+#[test]
+fn day_1_part_2() {
+    assert_answer(&Day1, &Variant::Real, Part::Two, include_str!("input"));
 }
@@ -1,4 +1,6 @@
-use crate::engine::input_engine::{read_day_input, read_day_input_example};
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
 
 struct CathodeRayTube {
     tube_value: isize,
@@ -82,11 +84,10 @@ fn parse_input(input: &Vec<String>) -> Vec<Command> {
 }
 
 fn execute_cycle_trigger(cycle_trigger: &usize, tube_value: &isize) -> isize {
-    println!("cycle {} tube {} - command", cycle_trigger, tube_value);
     *tube_value * (*cycle_trigger as isize)
 }
 
-fn part_1(input: &Vec<String>) {
+fn part_1(input: &Vec<String>) -> isize {
     let mut cathode = CathodeRayTube::new();
     let mut cycle_triggers: Vec<usize> = vec![220, 180, 140, 100, 60, 20];
 
@@ -111,21 +112,20 @@ fn part_1(input: &Vec<String>) {
             }
         }
     }
-    println!("{}", result)
+    result
 }
 
 fn get_cycle_visual() -> [bool; 40] {
     [false; 40]
 }
 
-fn print_cycle_visual(cycle_visual: &[bool; 40]) {
-    let line = cycle_visual.iter().fold("".to_string(), |t, v| {
+fn cycle_visual_line(cycle_visual: &[bool; 40]) -> String {
+    cycle_visual.iter().fold("".to_string(), |t, v| {
         if *v {
             return t + "#";
         }
         t + "."
-    });
-    println!("{}", line)
+    })
 }
 
 fn print_cycle_on_visual(
@@ -152,10 +152,14 @@ fn print_cycle_on_visual(
 fn test_cycle_visual() {
     let mut cycle_visual = get_cycle_visual();
     cycle_visual = print_cycle_on_visual(cycle_visual, &4, &43);
-    print_cycle_visual(&cycle_visual)
+    assert_eq!(
+        cycle_visual_line(&cycle_visual),
+        "...##..................................."
+    );
 }
 
-fn part_2(input: &Vec<String>) {
+fn part_2(input: &Vec<String>) -> String {
+    let mut screen: Vec<String> = vec![];
     let mut cathode = CathodeRayTube::new();
     let mut cycle_triggers: Vec<usize> = vec![240, 200, 160, 120, 80, 40];
 
@@ -170,7 +174,7 @@ fn part_2(input: &Vec<String>) {
         if current_command == "addx" {
             if (started + 1) >= next_cycle_trigger && !cycle_triggers.is_empty() {
                 next_cycle_trigger = cycle_triggers.pop().unwrap();
-                print_cycle_visual(&next_cycle_line);
+                screen.push(cycle_visual_line(&next_cycle_line));
                 next_cycle_line = get_cycle_visual();
             }
             next_cycle_line =
@@ -178,30 +182,60 @@ fn part_2(input: &Vec<String>) {
         }
         if cathode.cycle_counter >= next_cycle_trigger && !cycle_triggers.is_empty() {
             next_cycle_trigger = cycle_triggers.pop().unwrap();
-            print_cycle_visual(&next_cycle_line);
+            screen.push(cycle_visual_line(&next_cycle_line));
             next_cycle_line = get_cycle_visual();
         }
         next_cycle_line =
             print_cycle_on_visual(next_cycle_line, &cathode.tube_value, &cathode.cycle_counter);
     }
-    print_cycle_visual(&next_cycle_line);
+    screen.push(cycle_visual_line(&next_cycle_line));
+    screen.join("\n")
 }
 
-#[allow(dead_code)]
-fn main() {
-    let day_name = file!();
-    let input = read_day_input(day_name);
-    let example_input = read_day_input_example(day_name);
+pub struct Day10;
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
 
-    println!("Part 1 - example input");
-    part_1(&example_input);
+    type Input<'a> = Vec<String>;
+    type Part1 = isize;
+    type Part2 = String;
 
-    println!("Part 1 - input");
-    part_1(&input);
-
-    println!("Part 2 - example input");
-    part_2(&example_input);
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input.lines().map(|line| line.to_string()).collect()
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    /// the screen, one line of pixels per row
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
 
-    println!("Part 2 - input");
-    part_2(&input);
+#[test]
+fn day_10_part_1_example() {
+    assert_answer(
+        &Day10,
+        &Variant::Example,
+        Part::One,
+        include_str!("input.example"),
+    );
+}
+#[test]
+fn day_10_part_1() {
+    assert_answer(&Day10, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_10_part_2_example() {
+    assert_answer(
+        &Day10,
+        &Variant::Example,
+        Part::Two,
+        include_str!("input.example"),
+    );
+}
+#[test]
+fn day_10_part_2() {
+    assert_answer(&Day10, &Variant::Real, Part::Two, include_str!("input"));
 }
@@ -1,3 +1,6 @@
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
 use eval::eval;
 use regex::Regex;
 use std::mem::swap;
@@ -156,7 +159,7 @@ fn test_u32_division() {
     assert_eq!(10 % 3, 1);
 }
 
-fn part_1(input: &str) {
+fn part_1(input: &str) -> u64 {
     let mut monkeys = parse_input(input);
     for _i in 0..20 {
         monkeys = execute_round(monkeys);
@@ -167,10 +170,10 @@ fn part_1(input: &str) {
         .for_each(|monkey| monkey_inspects.push(monkey.inspect_count));
     monkey_inspects.sort_by(|a, b| b.cmp(a));
     assert!(monkey_inspects.len() >= 2);
-    println!("{}", monkey_inspects[0] * monkey_inspects[1])
+    monkey_inspects[0] * monkey_inspects[1]
 }
 
-fn part_2(input: &str) {
+fn part_2(input: &str) -> u64 {
     let mut monkeys = parse_input(input);
     for _i in 0..10000 {
         monkeys = execute_round_2(monkeys);
@@ -182,23 +185,52 @@ fn part_2(input: &str) {
     monkey_inspects.sort_by(|a, b| b.cmp(a));
 
     assert!(monkey_inspects.len() >= 2);
-    println!("{}", monkey_inspects[0] * monkey_inspects[1])
+    monkey_inspects[0] * monkey_inspects[1]
 }
 
-#[allow(dead_code)]
-fn main() {
-    let raw_input_example = include_str!("input.example");
-    let raw_input = include_str!("input");
+pub struct Day11;
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
 
-    println!("Part 1 - example input");
-    part_1(raw_input_example);
+    type Input<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    println!("Part 1 - input");
-    part_1(raw_input);
-
-    println!("Part 2 - example input");
-    part_2(raw_input_example);
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
 
-    println!("Part 2 - input");
-    part_2(raw_input);
+#[test]
+fn day_11_part_1_example() {
+    assert_answer(
+        &Day11,
+        &Variant::Example,
+        Part::One,
+        include_str!("input.example"),
+    );
+}
+#[test]
+fn day_11_part_1() {
+    assert_answer(&Day11, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_11_part_2_example() {
+    assert_answer(
+        &Day11,
+        &Variant::Example,
+        Part::Two,
+        include_str!("input.example"),
+    );
+}
+#[test]
+fn day_11_part_2() {
+    assert_answer(&Day11, &Variant::Real, Part::Two, include_str!("input"));
 }
@@ -1,3 +1,7 @@
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
+
 use crate::days::day_13::signal_value::SignalValue;
 
 use super::{
@@ -5,7 +9,6 @@ use super::{
     signal_value::{compare_signal_pair, SignalProcessing},
 };
 
-#[allow(dead_code)]
 fn part_1(input: &str) -> isize {
     let pairs = parse_day_13_input(input);
     let mut counter: isize = 0;
@@ -16,7 +19,6 @@ fn part_1(input: &str) -> isize {
     }
     counter
 }
-#[allow(dead_code)]
 fn part_2(input: &str) -> usize {
     let mut list = parse_input_part_2(input);
     let extra_element_2 =
@@ -37,27 +39,39 @@ fn part_2(input: &str) -> usize {
     (index_2 + 1) * (index_6 + 1)
 }
 
+pub struct Day13;
+impl Solution for Day13 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+
+    type Input<'a> = &'a str;
+    type Part1 = isize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
+
 #[test]
 fn day_12_part_1_example() {
-    let raw_input_example = include_str!("input.example");
-    let result = part_1(raw_input_example);
-    assert_eq!(result, 13);
+    assert_answer(&Day13, &Variant::Example, Part::One, include_str!("input.example"));
 }
 #[test]
 fn day_12_part_1() {
-    let input = include_str!("input");
-    let result = part_1(input);
-    println!("{}", result);
-    assert_eq!(part_1(input), 5198);
+    assert_answer(&Day13, &Variant::Real, Part::One, include_str!("input"));
 }
 #[test]
 fn day_12_part_2_example() {
-    let input = include_str!("input.example");
-    assert_eq!(part_2(input), 140);
+    assert_answer(&Day13, &Variant::Example, Part::Two, include_str!("input.example"));
 }
 #[test]
 fn day_12_part_2() {
-    let input = include_str!("input");
-    println!("{}", part_2(input));
-    assert_eq!(part_2(input), 22344);
+    assert_answer(&Day13, &Variant::Real, Part::Two, include_str!("input"));
 }
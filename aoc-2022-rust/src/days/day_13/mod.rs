@@ -1,4 +1,4 @@
-mod main_13;
+pub mod main_13;
 mod input_parser;
 mod signal_value;
 mod signal_pair;
@@ -1,3 +1,7 @@
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
+
 use crate::engine::grid_engine::Location;
 
 use super::regolith_reservoir::RegolithReservoir;
@@ -21,7 +25,6 @@ fn parse_input(input: &str) -> Vec<Vec<Location>> {
         .collect()
 }
 
-#[allow(dead_code)]
 fn part_1(input: &str) -> usize {
     let rock_location_sequences = parse_input(input);
     let mut cave = RegolithReservoir::new();
@@ -36,7 +39,6 @@ fn part_1(input: &str) -> usize {
     cave.print();
     sand_counter
 }
-#[allow(dead_code)]
 fn part_2(input: &str) -> usize {
     let rock_location_sequences = parse_input(input);
     let mut cave = RegolithReservoir::new();
@@ -53,27 +55,55 @@ fn part_2(input: &str) -> usize {
     sand_counter + 1
 }
 
+pub struct Day14;
+impl Solution for Day14 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
+
 #[test]
 fn day_12_part_1_example() {
-    let raw_input_example = include_str!("input.example");
-    let result = part_1(raw_input_example);
-    assert_eq!(result, 24);
+    assert_answer(&Day14, &Variant::Example, Part::One, include_str!("input.example"));
 }
 #[test]
 fn day_12_part_1() {
-    let input = include_str!("input");
-    let result = part_1(input);
-    println!("{}", result);
-    assert_eq!(part_1(input), 1406);
+    assert_answer(&Day14, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_14_render_example() {
+    use aoc_common::grid::Color;
+
+    let mut cave = RegolithReservoir::new();
+    for sequence in parse_input(include_str!("input.example")) {
+        cave.apply_rock_location_sequence(&sequence);
+    }
+    while cave.drop_sand_grain() {}
+    // x 494 - 503, y 0 - 9
+    let image = cave.to_image(2);
+    assert_eq!((image.width(), image.height()), (20, 20));
+    assert_eq!(image.get(0, 19), Some(Color::GRAY));
+    assert_eq!(image.get(12, 16), Some(Color::YELLOW));
+    assert_eq!(image.get(12, 0), Some(Color::BLACK));
 }
 #[test]
 fn day_12_part_2_example() {
-    let input = include_str!("input.example");
-    assert_eq!(part_2(input), 93);
+    assert_answer(&Day14, &Variant::Example, Part::Two, include_str!("input.example"));
 }
 #[test]
 fn day_12_part_2() {
-    let input = include_str!("input");
-    println!("{}", part_2(input));
-    assert_eq!(part_2(input), 20870);
+    assert_answer(&Day14, &Variant::Real, Part::Two, include_str!("input"));
 }
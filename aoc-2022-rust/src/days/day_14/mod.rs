@@ -1,3 +1,3 @@
-mod main_14;
+pub mod main_14;
 mod regolith_reservoir;
 mod cave_material;
@@ -1,3 +1,6 @@
+#[cfg(test)]
+use aoc_common::grid::{Color, Grid as SharedGrid, Image};
+
 use crate::engine::{
     grid::{
         boundaries::Boundaries,
@@ -62,6 +65,16 @@ impl RegolithReservoir {
         });
     }
 
+    /// rock in gray and sand in yellow, `scale` pixels per cell
+    #[cfg(test)]
+    pub fn to_image(&self, scale: usize) -> Image {
+        self.grid.to_image(scale, |cell| match cell.map(|cell| &cell.material) {
+            Some(CaveMaterial::Rock) => Color::GRAY,
+            Some(CaveMaterial::Sand) | Some(CaveMaterial::RestedSand) => Color::YELLOW,
+            _ => Color::BLACK,
+        })
+    }
+
     fn next_sand_location(&self, x: isize, y: isize, max_y: Option<isize>) -> Option<Location> {
         if max_y.is_some() && y >= max_y.unwrap() {
             return None;
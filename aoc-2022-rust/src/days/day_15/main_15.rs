@@ -1,6 +1,12 @@
+use aoc_common::{input::Variant, solution::Solution};
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, solution::Part};
 use regex::Regex;
 
-use crate::engine::grid_engine::Location;
+use crate::engine::{
+    grid_engine::Location,
+    manhattan::{find_uncovered, row_coverage, Diamond},
+};
 
 use super::sensor::Sensor;
 
@@ -39,102 +45,90 @@ fn parse_input(input: &str) -> Vec<Sensor> {
         .collect()
 }
 
-fn get_x_boundaries(sensors: &Vec<Sensor>) -> (isize, isize) {
-    let mut min: isize = 0;
-    let mut max: isize = 0;
+fn areas(sensors: &[Sensor]) -> Vec<Diamond> {
+    sensors.iter().map(|sensor| sensor.area.clone()).collect()
+}
+
+fn count_impossible_fields(sensors: &Vec<Sensor>, y: isize) -> usize {
+    let mut covered = row_coverage(&areas(sensors), y);
     for sensor in sensors {
-        let current_min = sensor.location.x - sensor.distance_q as isize;
-        let current_max = sensor.location.x + sensor.distance_q as isize;
-        if current_min < min {
-            min = current_min;
-        }
-        if current_max > max {
-            max = current_max;
+        if sensor.closest_beacon.y == y {
+            covered.remove(sensor.closest_beacon.x..=sensor.closest_beacon.x);
         }
     }
-    return (min, max);
+    covered.len() as usize
 }
 
-fn count_impossible_fields(sensors: &Vec<Sensor>, y: u32, min_x: isize, max_x: isize) -> usize {
-    let mut count: usize = 0;
-    for x in min_x..=max_x {
-        let mut possible = true;
-        for sensor in sensors {
-            if !sensor.can_be_beacon(x, y as isize) {
-                possible = false;
-                break;
-            }
-        }
-        if !possible {
-            count += 1;
-        }
+fn part_1(sensors: &Vec<Sensor>, row_line: isize) -> usize {
+    count_impossible_fields(sensors, row_line)
+}
+fn part_2(sensors: &[Sensor], max_position: isize) -> usize {
+    let min = Location { x: 0, y: 0 };
+    let max = Location {
+        x: max_position,
+        y: max_position,
+    };
+    match find_uncovered(&areas(sensors), &min, &max) {
+        None => 0,
+        Some(l) => l.x as usize * 4000000 + l.y as usize,
     }
-    count
 }
 
-#[allow(dead_code)]
-fn part_1(input: &str, row_line: u32) -> usize {
-    let sensors = parse_input(input);
-    let x_boundaries = get_x_boundaries(&sensors);
-    let min_x = x_boundaries.0;
-    let max_x = x_boundaries.1;
-    count_impossible_fields(&sensors, row_line, min_x, max_x)
+/// the sensors, with the row to check and the search limit of the puzzle
+pub struct Scan {
+    sensors: Vec<Sensor>,
+    row: isize,
+    limit: isize,
 }
-#[allow(dead_code)]
-fn part_2(input: &str, max_position: isize) -> usize {
-    let sensors = parse_input(input);
-    let mut location: Option<Location> = None;
-    for y in 0..=max_position {
-        let mut x = 0;
-        loop {
-            let blocking_sensor = sensors
-                .iter()
-                .find(|sensor| !sensor.can_be_unknown_beacon(x, y));
-            if blocking_sensor.is_none() {
-                location = Some(Location { x, y });
-                break;
-            } else {
-                x = blocking_sensor.unwrap().get_max_x(y);
-            }
-            x += 1;
-            if x > max_position {
-                break;
-            }
+
+pub struct Day15;
+impl Solution for Day15 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Scan;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        Scan {
+            sensors: parse_input(input),
+            row: 2000000,
+            limit: 4000000,
         }
-        if location.is_some() {
-            break;
+    }
+    /// the example checks a smaller row & search area
+    fn parse_variant<'a>(&self, input: &'a str, variant: &Variant) -> Self::Input<'a> {
+        match variant {
+            Variant::Example => Scan {
+                sensors: parse_input(input),
+                row: 10,
+                limit: 20,
+            },
+            _ => self.parse(input),
         }
     }
-    match location {
-        None => 0,
-        Some(l) => l.x as usize * 4000000 + l.y as usize,
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(&input.sensors, input.row)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(&input.sensors, input.limit)
     }
 }
 
 #[test]
 fn day_15_part_1_example() {
-    let raw_input_example = include_str!("input.example");
-    let result = part_1(raw_input_example, 10);
-    println!("{}", result);
-    assert_eq!(result, 26);
+    assert_answer(&Day15, &Variant::Example, Part::One, include_str!("input.example"));
 }
 #[test]
 fn day_15_part_1() {
-    let input = include_str!("input");
-    let result = part_1(input, 2000000);
-    println!("{}", result);
-    assert!(result > 4096105);
-    assert_eq!(result, 4883971);
+    assert_answer(&Day15, &Variant::Real, Part::One, include_str!("input"));
 }
 #[test]
 fn day_15_part_2_example() {
-    let input = include_str!("input.example");
-    assert_eq!(part_2(input, 20), 56000011);
+    assert_answer(&Day15, &Variant::Example, Part::Two, include_str!("input.example"));
 }
 #[test]
 fn day_15_part_2() {
-    let input = include_str!("input");
-    let result = part_2(input, 4000000);
-    println!("{}", result);
-    assert_eq!(result, 12691026767556);
+    assert_answer(&Day15, &Variant::Real, Part::Two, include_str!("input"));
 }
@@ -1,3 +1,3 @@
-mod main_15;
+pub mod main_15;
 mod sensor;
 mod beacon_map;
@@ -1,3 +1,7 @@
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
+
 use crate::days::day_16::valve::Valve;
 
 use super::valve::{ValveIteration2, ValveMap, ValveMapTrait};
@@ -123,7 +127,6 @@ fn iterate_options_2(valves: Vec<&Valve>, iteration: &mut ValveIteration2) -> us
     iteration.pressure_release
 }
 
-#[allow(dead_code)]
 pub fn part_1(input: &str) -> usize {
     let valve_map = parse_input(input);
     let mut valve_iteration = ValveIteration {
@@ -139,7 +142,6 @@ pub fn part_1(input: &str) -> usize {
         &mut valve_iteration,
     )
 }
-#[allow(dead_code)]
 fn part_2(input: &str) -> usize {
     let valve_map = parse_input(input);
     // todo!("add AA to open valve ids");
@@ -162,6 +164,26 @@ fn part_2(input: &str) -> usize {
     iterate_options_2(valve_map.values().collect(), &mut valve_iteration)
 }
 
+pub struct Day16;
+impl Solution for Day16 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 16;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
+
 pub fn day_16_part_1_run() {
     let input = include_str!("input");
     let result = part_1(input);
@@ -178,27 +200,17 @@ pub fn day_16_part_2_run() {
 
 #[test]
 fn day_16_part_1_example() {
-    let raw_input_example = include_str!("input.example");
-    let result = part_1(raw_input_example);
-    println!("{}", result);
-    assert_eq!(result, 1651);
+    assert_answer(&Day16, &Variant::Example, Part::One, include_str!("input.example"));
 }
 #[test]
 fn day_16_part_1() {
-    let input = include_str!("input");
-    let result = part_1(input);
-    println!("{}", result);
-    assert_eq!(result, 1584);
+    assert_answer(&Day16, &Variant::Real, Part::One, include_str!("input"));
 }
 #[test]
 fn day_16_part_2_example() {
-    let input = include_str!("input.example");
-    assert_eq!(part_2(input), 1707);
+    assert_answer(&Day16, &Variant::Example, Part::Two, include_str!("input.example"));
 }
 #[test]
 fn day_16_part_2() {
-    let input = include_str!("input");
-    let result = part_2(input);
-    println!("{}", result);
-    assert_eq!(result, 2052); // 42,877 sec in js
+    assert_answer(&Day16, &Variant::Real, Part::Two, include_str!("input")); // 42,877 sec in js
 }
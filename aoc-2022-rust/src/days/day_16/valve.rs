@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use aoc_common::graph::Graph;
 use fxhash::FxHashMap;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -70,43 +71,22 @@ pub trait ValveMapTrait {
 }
 impl ValveMapTrait for ValveMap {
     fn determine_valve_steps(&mut self) {
-        loop {
-            let mut triggered = false;
-            let ids: Vec<String> = self.keys().map(|id| id.clone()).collect();
-
-            ids.iter().for_each(|id| {
-                let mut valve_step_updates: Vec<(String, usize)> = vec![];
-                {
-                    let valve_borrow = self.get(id).unwrap();
-
-                    for (direct_id, direct_valve_steps) in valve_borrow.valve_steps.iter() {
-                        let direct_valve = self.get(direct_id).unwrap();
-                        for (next_id, next_valve_steps) in direct_valve.valve_steps.iter() {
-                            if next_id == id {
-                                continue;
-                            }
-                            let total_steps = direct_valve_steps + next_valve_steps;
-                            let valve_to_direct_steps = valve_borrow.valve_steps.get(next_id);
-                            if valve_to_direct_steps.is_none()
-                                || valve_to_direct_steps.unwrap() > &total_steps
-                            {
-                                triggered = true;
-                                valve_step_updates.push((next_id.clone(), total_steps));
-                            }
-                        }
-                    }
-                }
-
-                let valve = self.get_mut(id).unwrap();
-                valve_step_updates.into_iter().for_each(|(id, steps)| {
-                    valve.valve_steps.insert(id, steps);
-                });
-            });
-            let _result = self.get_mut(&String::from("AA"));
-            if !triggered {
-                break;
+        let mut graph = Graph::new();
+        for valve in self.values() {
+            graph.node(&valve.id);
+            for target_id in valve.direct_target_ids.iter() {
+                graph.add_edge(&valve.id, target_id, 1);
             }
         }
+        let distances = graph.all_pairs_bfs();
+        for valve in self.values_mut() {
+            let from = graph.id(&valve.id).unwrap();
+            valve.valve_steps = distances
+                .from(from)
+                .filter(|(to, _)| *to != from)
+                .map(|(to, steps)| (graph.name(to).to_string(), steps))
+                .collect();
+        }
     }
 
     fn new<'a>(valves: Vec<Valve>) -> Self {
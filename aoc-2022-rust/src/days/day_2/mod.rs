@@ -1,5 +1,7 @@
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
 use std::fmt;
-use std::fs;
 
 #[derive(Debug)]
 enum GameOptions {
@@ -25,7 +27,7 @@ impl fmt::Display for GameOptions {
 }
 
 #[derive(Debug)]
-struct GameCommand {
+pub struct GameCommand {
     game_option: Vec<GameOptions>,
     raw_value: String,
 }
@@ -40,9 +42,8 @@ impl fmt::Display for GameCommand {
     }
 }
 
-fn parse_input(input_path: &str) -> Vec<GameCommand> {
+fn parse_input(contents: &str) -> Vec<GameCommand> {
     let mut output: Vec<GameCommand> = Vec::new();
-    let contents = fs::read_to_string(input_path).expect("Should have been able to read the file");
     let raw_input: Vec<&str> = contents.split('\n').collect();
     for s in raw_input {
         if s.is_empty() {
@@ -159,32 +160,39 @@ fn get_score_part_1(game_command: &GameCommand) -> u32 {
     option_score + game_score
 }
 
-#[allow(dead_code)]
-fn main() {
-    let input_example_path = "src/bin/day2/input.example";
-    let input_path = "src/bin/day2/input";
-
-    println!("Part 1 - example input");
-    let game_example = parse_input(input_example_path);
-    let example_score = game_example
-        .into_iter()
-        .fold(0, |t, v| t + get_score_part_1(&v));
-    assert!(example_score == 15);
-
-    println!("Part 1 - input");
-    let game = parse_input(input_path);
-    let score = game.into_iter().fold(0, |t, v| t + get_score_part_1(&v));
-    println!("part 1 - score {}", score);
-
-    println!("Part 2 - example input");
-    let example_part2_score = parse_input(input_example_path)
-        .into_iter()
-        .fold(0, |t, v| t + part2_get_game_score(&v));
-    assert!(example_part2_score == 12);
-
-    println!("Part 2 - input");
-    let part2_score = parse_input(input_path)
-        .into_iter()
-        .fold(0, |t, v| t + part2_get_game_score(&v));
-    println!("part 2 - score {}", part2_score);
+pub struct Day2;
+impl Solution for Day2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<GameCommand>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        parse_input(input)
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        input.iter().fold(0, |t, v| t + get_score_part_1(v))
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        input.iter().fold(0, |t, v| t + part2_get_game_score(v))
+    }
+}
+
+#[test]
+fn day_2_part_1_example() {
+    assert_answer(&Day2, &Variant::Example, Part::One, include_str!("input.example"));
+}
+#[test]
+fn day_2_part_1() {
+    assert_answer(&Day2, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_2_part_2_example() {
+    assert_answer(&Day2, &Variant::Example, Part::Two, include_str!("input.example"));
+}
+#[test]
+fn day_2_part_2() {
+    assert_answer(&Day2, &Variant::Real, Part::Two, include_str!("input"));
 }
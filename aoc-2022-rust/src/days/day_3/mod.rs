@@ -1,6 +1,8 @@
 use substring::Substring;
 
-use crate::engine::input_engine::{read_day_input, read_day_input_example};
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
 
 #[derive(Clone)]
 struct RuckSack {
@@ -63,13 +65,11 @@ fn find_wrong_rucksack_item(rucksack: &RuckSack) -> u32 {
         .unwrap()
 }
 
-fn part_1(input: &Vec<String>) {
-    let result = parse_input(input)
+fn part_1(input: &Vec<String>) -> u32 {
+    parse_input(input)
         .iter()
         .map(find_wrong_rucksack_item)
-        .sum::<u32>();
-
-    println!("{}", result)
+        .sum::<u32>()
 }
 
 fn find_shared_priority(input: &Vec<RuckSack>) -> u32 {
@@ -100,7 +100,7 @@ fn find_shared_priority(input: &Vec<RuckSack>) -> u32 {
     remaining_prios[0]
 }
 
-fn part_2(input: &Vec<String>) {
+fn part_2(input: &Vec<String>) -> u32 {
     let result = parse_input(input);
     let mut i = 0usize;
     let mut total = 0u32;
@@ -112,27 +112,44 @@ fn part_2(input: &Vec<String>) {
             break;
         }
     }
-
-    println!("{}", total)
+    total
 }
 
-#[allow(dead_code)]
-pub fn main() {
-    let day_name = file!();
-    let input = read_day_input(day_name);
-    let example_input = read_day_input_example(day_name);
+pub struct Day3;
+impl Solution for Day3 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
 
-    println!("Part 1 - example input");
-    part_1(&example_input);
+    type Input<'a> = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    println!("Part 1 - input");
-    part_1(&input);
-
-    println!("Part 2 - example input");
-    part_2(&example_input);
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input.lines().map(|line| line.to_string()).collect()
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
 
-    println!("Part 2 - input");
-    part_2(&input);
+#[test]
+fn day_3_part_1_example() {
+    assert_answer(&Day3, &Variant::Example, Part::One, include_str!("input.example"));
+}
+#[test]
+fn day_3_part_1() {
+    assert_answer(&Day3, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_3_part_2_example() {
+    assert_answer(&Day3, &Variant::Example, Part::Two, include_str!("input.example"));
+}
+#[test]
+fn day_3_part_2() {
+    assert_answer(&Day3, &Variant::Real, Part::Two, include_str!("input"));
 }
 
 #[cfg(test)]
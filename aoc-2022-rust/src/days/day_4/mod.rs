@@ -0,0 +1,126 @@
+use aoc_common::interval::IntervalSet;
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
+use regex::Regex;
+
+struct Elf {
+    section_start: usize,
+    section_end: usize,
+}
+
+struct ElfPair {
+    elf_1: Elf,
+    elf_2: Elf,
+}
+
+fn parse_input(input: &Vec<String>) -> Vec<ElfPair> {
+    let mut output: Vec<ElfPair> = Vec::new();
+    let re = Regex::new(r"(\d+)-(\d+),(\d+)-(\d+)").unwrap();
+    for line in input.iter() {
+        let re_match = re.captures(line).unwrap();
+        output.push(ElfPair {
+            elf_1: Elf {
+                section_start: usize::from_str_radix(&re_match[1], 10).unwrap(),
+                section_end: usize::from_str_radix(&re_match[2], 10).unwrap(),
+            },
+            elf_2: Elf {
+                section_start: usize::from_str_radix(&re_match[3], 10).unwrap(),
+                section_end: usize::from_str_radix(&re_match[4], 10).unwrap(),
+            },
+        });
+    }
+    output
+}
+
+impl Elf {
+    fn sections(&self) -> IntervalSet<usize> {
+        [self.section_start..=self.section_end]
+            .into_iter()
+            .collect()
+    }
+}
+
+fn is_elf_overlapped_by_elf(elf: &Elf, other_elf: &Elf) -> bool {
+    other_elf
+        .sections()
+        .contains_range(elf.section_start..=elf.section_end)
+}
+
+fn is_pair_internally_fully_overlapping(pair: &ElfPair) -> bool {
+    is_elf_overlapped_by_elf(&pair.elf_1, &pair.elf_2)
+        || is_elf_overlapped_by_elf(&pair.elf_2, &pair.elf_1)
+}
+
+fn is_pair_internally_partly_overlapping(ElfPair { elf_1, elf_2, .. }: &ElfPair) -> bool {
+    elf_1
+        .sections()
+        .overlaps(elf_2.section_start..=elf_2.section_end)
+}
+
+fn part_1(input: &Vec<String>) -> usize {
+    let elf_pairs = parse_input(input);
+    let result = elf_pairs
+        .iter()
+        .filter(|pair| is_pair_internally_fully_overlapping(pair))
+        .map(|item| item.clone())
+        .collect::<Vec<&ElfPair>>();
+    result.len()
+}
+
+fn part_2(input: &Vec<String>) -> usize {
+    let elf_pairs = parse_input(input);
+    let result = elf_pairs
+        .iter()
+        .filter(|pair| is_pair_internally_partly_overlapping(pair))
+        .map(|item| item.clone())
+        .collect::<Vec<&ElfPair>>();
+    result.len()
+}
+
+pub struct Day4;
+impl Solution for Day4 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input.lines().map(|line| line.to_string()).collect()
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
+
+#[test]
+fn day_4_part_1_example() {
+    assert_answer(
+        &Day4,
+        &Variant::Example,
+        Part::One,
+        include_str!("input.example"),
+    );
+}
+#[test]
+fn day_4_part_1() {
+    assert_answer(&Day4, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_4_part_2_example() {
+    assert_answer(
+        &Day4,
+        &Variant::Example,
+        Part::Two,
+        include_str!("input.example"),
+    );
+}
+#[test]
+fn day_4_part_2() {
+    assert_answer(&Day4, &Variant::Real, Part::Two, include_str!("input"));
+}
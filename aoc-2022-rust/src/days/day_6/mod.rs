@@ -1,7 +1,9 @@
-use crate::engine::input_engine::{read_day_input, read_day_input_example};
+use aoc_common::solution::{Answer, Solution};
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
 use std::ops::Index;
 
-fn part_1(input: &Vec<String>) {
+fn part_1(input: &Vec<String>) -> usize {
     let result = input.index(0);
     let mut fragment: Vec<char> = Vec::new();
     let mut parsed: usize = 0;
@@ -15,17 +17,12 @@ fn part_1(input: &Vec<String>) {
             break;
         }
     }
-    println!("{}", parsed);
+    parsed
 }
 
-fn part_2(input: &Vec<String>) {
+fn part_2(input: &Vec<String>) -> Option<usize> {
     let result = input.index(0);
-    for i in 14..=result.len() {
-        if is_start_of_message_marker(&result[(i - 14)..i]) {
-            println!("{}", i);
-            return;
-        }
-    }
+    (14..=result.len()).find(|i| is_start_of_message_marker(&result[(i - 14)..*i]))
 }
 
 fn is_start_of_message_marker(piece: &str) -> bool {
@@ -39,21 +36,49 @@ fn is_start_of_message_marker(piece: &str) -> bool {
     true
 }
 
-#[allow(dead_code)]
-fn main() {
-    let day_name = file!();
-    let input = read_day_input(day_name);
-    let example_input = read_day_input_example(day_name);
-
-    println!("Part 1 - example input");
-    part_1(&example_input);
+pub struct Day6;
+impl Solution for Day6 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
 
-    println!("Part 1 - input");
-    part_1(&input);
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = Answer;
 
-    println!("Part 2 - example input");
-    part_2(&example_input);
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input.lines().map(|line| line.to_string()).collect()
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
 
-    println!("Part 2 - input");
-    part_2(&input);
+#[test]
+fn day_6_part_1_example() {
+    assert_answer(
+        &Day6,
+        &Variant::Example,
+        Part::One,
+        include_str!("input.example"),
+    );
+}
+#[test]
+fn day_6_part_1() {
+    assert_answer(&Day6, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_6_part_2_example() {
+    assert_answer(
+        &Day6,
+        &Variant::Example,
+        Part::Two,
+        include_str!("input.example"),
+    );
+}
+#[test]
+fn day_6_part_2() {
+    assert_answer(&Day6, &Variant::Real, Part::Two, include_str!("input"));
 }
@@ -0,0 +1,330 @@
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
+
+struct TreePatch {
+    trees: Vec<Vec<Tree>>,
+    max_x: usize,
+    max_y: usize,
+}
+
+struct Tree {
+    height: u32,
+    v_left: bool,
+    v_right: bool,
+    v_top: bool,
+    v_bottom: bool,
+}
+
+impl TreePatch {
+    pub fn calculate_trees_visibility(mut self) -> Self {
+        // top
+        for x in 0..self.max_x {
+            let outer_top_element = self.trees.get_mut(0).unwrap().get_mut(x).unwrap();
+            outer_top_element.v_top = true;
+            let mut max_tree_height = outer_top_element.height;
+            for y in 1..self.max_y {
+                let next_tree = self.trees.get_mut(y).unwrap().get_mut(x).unwrap();
+                if next_tree.height > max_tree_height {
+                    next_tree.v_top = true;
+                    max_tree_height = next_tree.height;
+                } else {
+                    next_tree.v_top = false;
+                }
+            }
+        }
+        // left
+        for y in 0..self.max_y {
+            let outer_left_tree = self.trees.get_mut(y).unwrap().get_mut(0).unwrap();
+            outer_left_tree.v_left = true;
+            let mut max_tree_height = outer_left_tree.height;
+            for x in 1..self.max_x {
+                let next_tree = self.trees.get_mut(y).unwrap().get_mut(x).unwrap();
+                if next_tree.height > max_tree_height {
+                    next_tree.v_left = true;
+                    max_tree_height = next_tree.height;
+                } else {
+                    next_tree.v_left = false;
+                }
+            }
+        }
+        // bottom
+        for x in 0..self.max_x {
+            let outer_bottom_element = self
+                .trees
+                .get_mut(self.max_y - 1)
+                .unwrap()
+                .get_mut(x)
+                .unwrap();
+            outer_bottom_element.v_bottom = true;
+            let mut max_tree_height = outer_bottom_element.height;
+            for y in 1..self.max_y {
+                let next_tree = self
+                    .trees
+                    .get_mut(self.max_y - 1 - y)
+                    .unwrap()
+                    .get_mut(x)
+                    .unwrap();
+                if next_tree.height > max_tree_height {
+                    next_tree.v_bottom = true;
+                    max_tree_height = next_tree.height;
+                } else {
+                    next_tree.v_bottom = false;
+                }
+            }
+        }
+        // right
+        for y in 0..self.max_y {
+            let outer_right_tree = self
+                .trees
+                .get_mut(y)
+                .unwrap()
+                .get_mut(self.max_x - 1)
+                .unwrap();
+            outer_right_tree.v_right = true;
+            let mut max_tree_height = outer_right_tree.height;
+            for x in 1..self.max_x {
+                let next_tree = self
+                    .trees
+                    .get_mut(y)
+                    .unwrap()
+                    .get_mut(self.max_x - 1 - x)
+                    .unwrap();
+                if next_tree.height > max_tree_height {
+                    next_tree.v_right = true;
+                    max_tree_height = next_tree.height;
+                } else {
+                    next_tree.v_right = false;
+                }
+            }
+        }
+        self
+    }
+
+    pub fn count_visible_trees(&self) -> usize {
+        let mut output: usize = 0;
+        for x in 0..self.max_x {
+            for y in 0..self.max_y {
+                if self.trees[y][x].is_visible() {
+                    output += 1;
+                }
+            }
+        }
+        output
+    }
+
+    pub fn calculate_best_view_score(&self) -> u32 {
+        let mut output: u32 = 0;
+        for x in 0..self.max_x {
+            for y in 0..self.max_y {
+                if x == 2 && y == 3 {
+                    let _test = true;
+                }
+                let score = self.calculate_view_score(x, y);
+                if score > output {
+                    output = score;
+                }
+            }
+        }
+        output
+    }
+
+    pub fn calculate_view_score(&self, x: usize, y: usize) -> u32 {
+        let score_right = self.calculate_view_score_right(x, y);
+        if score_right == 0 {
+            return 0;
+        }
+
+        let score_left = self.calculate_view_score_left(x, y);
+        if score_left == 0 {
+            return 0;
+        }
+
+        let score_bottom = self.calculate_view_score_bottom(x, y);
+        if score_bottom == 0 {
+            return 0;
+        }
+
+        let score_top = self.calculate_view_score_top(x, y);
+        if score_top == 0 {
+            return 0;
+        }
+
+        score_right * score_left * score_bottom * score_top
+    }
+
+    pub fn calculate_view_score_right(&self, x_start: usize, y_start: usize) -> u32 {
+        if x_start >= self.max_x - 1 {
+            return 0;
+        }
+        if x_start == self.max_x - 2 {
+            return 1;
+        }
+        let mut output: u32 = 0;
+        let max_height = self.trees[y_start][x_start].height;
+        for x in (x_start + 1)..self.max_x {
+            output += 1;
+            if self.trees[y_start][x].height >= max_height {
+                break;
+            }
+        }
+        output
+    }
+
+    pub fn calculate_view_score_left(&self, x_start: usize, y_start: usize) -> u32 {
+        if x_start == 0 {
+            return 0;
+        }
+        if x_start == 1 {
+            return 1;
+        }
+        let mut output: u32 = 0;
+        let max_height = self.trees[y_start][x_start].height;
+        for x in 0..=(x_start - 1) {
+            output += 1;
+            if self.trees[y_start][x_start - 1 - x].height >= max_height {
+                break;
+            }
+        }
+        output
+    }
+
+    pub fn calculate_view_score_bottom(&self, x_start: usize, y_start: usize) -> u32 {
+        if y_start >= self.max_y - 1 {
+            return 0;
+        }
+        if y_start == self.max_y - 2 {
+            return 1;
+        }
+        let mut output: u32 = 0;
+        let max_height = self.trees[y_start][x_start].height;
+        for y in (y_start + 1)..self.max_y {
+            output += 1;
+            if self.trees[y][x_start].height >= max_height {
+                break;
+            }
+        }
+        output
+    }
+
+    pub fn calculate_view_score_top(&self, x_start: usize, y_start: usize) -> u32 {
+        if y_start == 0 {
+            return 0;
+        }
+        if y_start == 1 {
+            return 1;
+        }
+        let mut output: u32 = 0;
+        let max_height = self.trees[y_start][x_start].height;
+        for y in 0..=(y_start - 1) {
+            output += 1;
+            if self.trees[y_start - 1 - y][x_start].height >= max_height {
+                break;
+            }
+        }
+        output
+    }
+}
+
+impl Tree {
+    pub fn is_visible(&self) -> bool {
+        self.v_top || self.v_left || self.v_right || self.v_bottom
+    }
+}
+
+#[test]
+fn test_split() {
+    let path = "12345";
+    println!("custom test");
+    for (i, part) in path
+        .chars()
+        .into_iter()
+        .map(|x| x.to_digit(10).unwrap())
+        .enumerate()
+    {
+        println!("{} - {}", i, part);
+    }
+}
+
+fn parse_input(input: &Vec<String>) -> TreePatch {
+    TreePatch {
+        max_y: input.len(),
+        max_x: input.get(0).unwrap().len(),
+        trees: input
+            .into_iter()
+            .map(|tree_line| {
+                tree_line
+                    .chars()
+                    .into_iter()
+                    .map(|height| Tree {
+                        height: height.to_digit(10).unwrap(),
+                        v_left: false,
+                        v_top: false,
+                        v_right: false,
+                        v_bottom: false,
+                    })
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+fn part_1(input: &Vec<String>) -> usize {
+    let mut result = parse_input(input);
+    result = result.calculate_trees_visibility();
+    // result.print();
+    result.count_visible_trees()
+}
+
+fn part_2(input: &Vec<String>) -> u32 {
+    let result = parse_input(input);
+    result.calculate_best_view_score()
+}
+
+pub struct Day8;
+impl Solution for Day8 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input.lines().map(|line| line.to_string()).collect()
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        // 50160 is too low
+        part_2(input)
+    }
+}
+
+#[test]
+fn day_8_part_1_example() {
+    assert_answer(
+        &Day8,
+        &Variant::Example,
+        Part::One,
+        include_str!("input.example"),
+    );
+}
+#[test]
+fn day_8_part_1() {
+    assert_answer(&Day8, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_8_part_2_example() {
+    assert_answer(
+        &Day8,
+        &Variant::Example,
+        Part::Two,
+        include_str!("input.example"),
+    );
+}
+#[test]
+fn day_8_part_2() {
+    assert_answer(&Day8, &Variant::Real, Part::Two, include_str!("input"));
+}
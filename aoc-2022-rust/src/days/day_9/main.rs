@@ -1,4 +1,8 @@
-use crate::engine::{input_engine::{read_day_input, read_day_input_example}, grid_engine::Grid};
+use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
+
+use crate::engine::grid_engine::Grid;
 
 struct RopeCell {
     tail_visited: bool,
@@ -126,38 +130,51 @@ struct Position {
     y: isize,
 }
 
-fn part_1(input: &Vec<String>) {
+fn part_1(input: &Vec<String>) -> usize {
     let mut field = RopeField::new(2);
     for line in input.iter() { execute_command(line, &mut field, false); }
-    let result = field.count_tail_fields();
-
-
-    println!("{}", result)
+    field.count_tail_fields()
 }
 
-fn part_2(input: &Vec<String>, print: bool) {
+fn part_2(input: &Vec<String>, print: bool) -> usize {
     let mut field = RopeField::new(10);
     for line in input.iter() { execute_command(line, &mut field, print); }
-    let result = field.count_tail_fields();
-    println!("{}", result)
+    field.count_tail_fields()
 }
 
-#[allow(dead_code)]
-fn main() {
-    let day_name = file!();
-    let input = read_day_input(&day_name);
-    let example_input = read_day_input_example(&day_name);
+pub struct Day9;
+impl Solution for Day9 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
 
-    println!("Part 1 - example input");
-    part_1(&example_input);
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("Part 1 - input");
-    part_1(&input);
-
-    println!("Part 2 - example input");
-    part_2(&example_input, true);
-
-    println!("Part 2 - input");
-    part_2(&input, false);
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input.lines().map(|line| line.to_string()).collect()
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input, false)
+    }
 }
 
+#[test]
+fn day_9_part_1_example() {
+    assert_answer(&Day9, &Variant::Example, Part::One, include_str!("input.example"));
+}
+#[test]
+fn day_9_part_1() {
+    assert_answer(&Day9, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_9_part_2_example() {
+    assert_answer(&Day9, &Variant::Example, Part::Two, include_str!("input.example"));
+}
+#[test]
+fn day_9_part_2() {
+    assert_answer(&Day9, &Variant::Real, Part::Two, include_str!("input"));
+}
@@ -1,7 +1,13 @@
+use aoc_common::registry::Registry;
+
 mod day_1;
 mod day_2;
 mod day_3;
+pub mod day_4;
+pub mod day_5;
 mod day_6;
+pub mod day_7;
+pub mod day_8;
 mod day_9;
 mod day_10;
 mod day_11;
@@ -12,3 +18,29 @@ mod day_15;
 pub mod day_16;
 mod day_16_1;
 mod day_17;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(day_1::Day1);
+    registry.register(day_2::Day2);
+    registry.register(day_3::Day3);
+    registry.register(day_4::Day4);
+    registry.register(day_5::Day5);
+    registry.register(day_6::Day6);
+    registry.register(day_7::Day7);
+    registry.register(day_8::Day8);
+    registry.register(day_9::main::Day9);
+    registry.register(day_10::Day10);
+    registry.register(day_11::Day11);
+    registry.register(day_12::main::Day12);
+    registry.register(day_13::main_13::Day13);
+    registry.register(day_14::main_14::Day14);
+    registry.register(day_15::main_15::Day15);
+    registry.register(day_16::main_16::Day16);
+}
+
+#[test]
+fn register_days() {
+    let mut registry = Registry::new();
+    register(&mut registry);
+    assert_eq!(registry.iter_year(2022).count(), 16);
+}
@@ -1,23 +1 @@
-pub struct Boundaries {
-    pub min_x: isize,
-    pub max_x: isize,
-    pub min_y: isize,
-    pub max_y: isize,
-}
-
-impl Boundaries {
-    pub fn update(&mut self, x: isize, y: isize) {
-        if x > self.max_x {
-            self.max_x = x;
-        }
-        if x < self.min_x {
-            self.min_x = x;
-        }
-        if y > self.max_y {
-            self.max_y = y;
-        }
-        if y < self.min_y {
-            self.min_y = y;
-        }
-    }
-}
+pub use aoc_common::grid::Boundaries;
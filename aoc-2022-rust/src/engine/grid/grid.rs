@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use aoc_common::grid::{self as shared, Position};
+
 use super::{boundaries::Boundaries, cell::{MapCell, GridCell}};
 
 pub struct Grid<T> {
@@ -75,3 +77,24 @@ impl<T> Grid<T> {
         }
     }
 }
+
+/// lets the older days use the shared grid functionality
+impl<T> shared::Grid<T> for Grid<T> {
+    fn boundaries(&self) -> Boundaries {
+        self.boundaries
+    }
+
+    fn get(&self, position: Position) -> Option<&T> {
+        self.borrow_cell(position.x, position.y)
+            .and_then(|cell| cell.borrow_value())
+    }
+
+    fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.borrow_mut_cell(position.x, position.y)
+            .and_then(|cell| cell.value.as_mut())
+    }
+
+    fn set(&mut self, position: Position, value: T) {
+        self.update_cell(position.x, position.y, value);
+    }
+}
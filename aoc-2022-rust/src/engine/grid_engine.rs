@@ -1,5 +1,10 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
+use aoc_common::grid::{FromCells, Grid as SharedGrid, Position, SparseGrid};
+
+pub use aoc_common::grid::Boundaries as MapBoundaries;
+
+#[derive(Debug, PartialEq, Eq)]
 pub struct Location {
     pub x: isize,
     pub y: isize,
@@ -13,6 +18,21 @@ impl Clone for Location {
     }
 }
 
+impl From<Location> for Position {
+    fn from(location: Location) -> Self {
+        Position::new(location.x, location.y)
+    }
+}
+
+impl From<Position> for Location {
+    fn from(position: Position) -> Self {
+        Location {
+            x: position.x,
+            y: position.y,
+        }
+    }
+}
+
 pub struct GridCell<T> {
     pub x: isize,
     pub y: isize,
@@ -42,24 +62,6 @@ pub struct Grid<T: Clone> {
     pub map: GridMap<T>,
 }
 
-pub struct MapBoundaries {
-    pub min_x: isize,
-    pub max_x: isize,
-    pub min_y: isize,
-    pub max_y: isize,
-}
-
-impl MapBoundaries {
-    pub fn default() -> Self {
-        MapBoundaries {
-            min_x: 0,
-            min_y: 0,
-            max_x: 0,
-            max_y: 0,
-        }
-    }
-}
-
 impl<T: Clone> Grid<T> {
     pub fn set_cell_value(&mut self, x: isize, y: isize, value: T) -> Rc<RefCell<GridCell<T>>> {
         let x_map = self.map.entry(y).or_default();
@@ -125,23 +127,18 @@ impl<T: Clone> Grid<T> {
     ) -> Self {
         let min_x = min_x_arg.unwrap_or(0);
         let min_y = min_y_arg.unwrap_or(0);
-        let max_x = 0;
-        let mut max_y = 0;
-
-        let mut map: GridMap<T> = HashMap::new();
-        for (y, line) in lines.iter().enumerate() {
-            max_y = min_y + y as isize;
-            map.entry(y as isize).or_insert_with(HashMap::new);
-            let parsed_values = parse_line(line.clone());
-            for (_x, _value) in parsed_values.into_iter().enumerate() {}
-        }
-        Grid {
-            min_x,
-            min_y,
-            max_x,
-            max_y,
-            map,
+        let mut grid = Grid::new(Some(MapBoundaries::from_position(Position::new(
+            min_x, min_y,
+        ))));
+        for (y, line) in lines.into_iter().enumerate() {
+            let y = min_y + y as isize;
+            grid.max_y = y;
+            grid.map.entry(y).or_default();
+            for (x, value) in parse_line(line).into_iter().enumerate() {
+                grid.set_cell_value(min_x + x as isize, y, value);
+            }
         }
+        grid
     }
 
     pub fn print(&self, print_value: fn(Option<T>) -> String) {
@@ -175,3 +172,43 @@ impl<T: Clone> Grid<T> {
         }
     }
 }
+
+impl<T: Clone> FromCells<T> for Grid<T> {
+    fn from_cells(boundaries: MapBoundaries, cells: Vec<(Position, T)>) -> Option<Self> {
+        let mut grid = Grid::new(Some(boundaries));
+        for (position, value) in cells {
+            grid.set_cell_value(position.x, position.y, value);
+        }
+        Some(grid)
+    }
+}
+
+/// copies the cells out of their `Rc<RefCell<..>>` wrappers into the shared grid
+impl<T: Clone> From<&Grid<T>> for SparseGrid<T> {
+    fn from(grid: &Grid<T>) -> Self {
+        let mut output = SparseGrid::with_boundaries(MapBoundaries {
+            min_x: grid.min_x,
+            max_x: grid.max_x,
+            min_y: grid.min_y,
+            max_y: grid.max_y,
+        });
+        for x_map in grid.map.values() {
+            for cell in x_map.values() {
+                let cell = cell.as_ref().borrow();
+                output.set(Position::new(cell.x, cell.y), cell.value.clone());
+            }
+        }
+        output
+    }
+}
+
+#[test]
+fn new_from_lines() {
+    let lines = vec!["123".to_string(), "45".to_string()];
+    let parse_line = |line: String| line.chars().map(|c| c.to_digit(10).unwrap()).collect();
+    let grid: Grid<u32> = Grid::new_from_lines(lines, parse_line, None, Some(1));
+    assert_eq!(grid.get_cell_value(2, 1), Some(3));
+    assert_eq!(grid.get_cell_value(1, 2), Some(5));
+    assert_eq!(grid.get_cell_value(2, 2), None);
+    assert_eq!((grid.min_y, grid.max_x, grid.max_y), (1, 2, 2));
+}
@@ -1,35 +1,17 @@
-use regex::Regex;
-use std::borrow::{Borrow, Cow};
-use std::fs;
+use aoc_common::input::{InputError, InputResolver, Variant};
+use aoc_common::solution::{Part, Puzzle};
 
-// TODO include_str! https://doc.rust-lang.org/std/macro.include_str.html#
-// this should be an improvement
-
-pub fn read_input_file(input_path: String) -> Vec<String> {
-    fs::read_to_string(&input_path)
-        .unwrap_or_else(|_| panic!("Should have been able to read the file {}", input_path))
-        .lines()
-        .map(|x| x.to_string())
-        .collect::<Vec<String>>()
-}
-
-fn get_binary_folder(file_path: &str) -> Cow<str> {
-    let re = Regex::new(r"/[^/]+$").unwrap();
-    re.replace(file_path, "")
-}
-
-fn get_input_path(directory: &str, file: &str) -> String {
-    format!("{}/{}", directory, file)
-}
-
-pub fn read_day_input(file_path: &str) -> Vec<String> {
-    let day_input_path = get_input_path(get_binary_folder(file_path).borrow(), "input");
-    println!("{}", &day_input_path);
-    read_input_file(day_input_path)
-}
-
-pub fn read_day_input_example(file_path: &str) -> Vec<String> {
-    let day_input_path = get_input_path(get_binary_folder(file_path).borrow(), "input.example");
-    println!("{}", &day_input_path);
-    read_input_file(day_input_path)
+/// Runs a day on its example and its real input, printing the answer of every part.
+pub fn run_day(puzzle: &dyn Puzzle) -> Result<(), InputError> {
+    let resolver = InputResolver::workspace();
+    for variant in [Variant::Example, Variant::Real] {
+        let input = resolver.read(puzzle.year(), puzzle.day(), &variant)?;
+        for part_run in puzzle.run(&input, &variant, &Part::ALL).parts {
+            println!(
+                "Part {} - {} input: {}",
+                part_run.part, variant, part_run.answer
+            );
+        }
+    }
+    Ok(())
 }
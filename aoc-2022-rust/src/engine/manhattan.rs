@@ -0,0 +1,207 @@
+use std::ops::RangeInclusive;
+
+use aoc_common::interval::IntervalSet;
+
+use super::grid_engine::Location;
+
+impl Location {
+    pub fn manhattan_distance(&self, other: &Location) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// A location turned 45 degrees: u = x + y, v = x - y. Manhattan distance
+/// becomes the largest difference of u or v, so a diamond is a square here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotated {
+    pub u: isize,
+    pub v: isize,
+}
+
+impl Rotated {
+    /// None when u and v differ in parity, those lie between the grid cells
+    pub fn to_location(&self) -> Option<Location> {
+        if (self.u - self.v) % 2 != 0 {
+            return None;
+        }
+        Some(Location {
+            x: (self.u + self.v) / 2,
+            y: (self.u - self.v) / 2,
+        })
+    }
+}
+
+impl From<&Location> for Rotated {
+    fn from(location: &Location) -> Self {
+        Rotated {
+            u: location.x + location.y,
+            v: location.x - location.y,
+        }
+    }
+}
+
+/// Every location within `radius` steps of the center, like the area a sensor
+/// can rule out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diamond {
+    pub center: Location,
+    pub radius: usize,
+}
+
+impl Diamond {
+    pub fn new(center: Location, radius: usize) -> Self {
+        Diamond { center, radius }
+    }
+
+    /// the smallest diamond around the center that reaches the edge
+    pub fn through(center: Location, edge: &Location) -> Self {
+        let radius = center.manhattan_distance(edge);
+        Diamond { center, radius }
+    }
+
+    pub fn contains(&self, location: &Location) -> bool {
+        self.center.manhattan_distance(location) <= self.radius
+    }
+
+    /// the x values of row y that are covered
+    pub fn row_coverage(&self, y: isize) -> Option<RangeInclusive<isize>> {
+        let reach = self.radius.checked_sub(self.center.y.abs_diff(y))? as isize;
+        Some(self.center.x - reach..=self.center.x + reach)
+    }
+
+    /// the square the diamond becomes in rotated coordinates, as (u, v) ranges
+    pub fn rotated(&self) -> (RangeInclusive<isize>, RangeInclusive<isize>) {
+        let Rotated { u, v } = Rotated::from(&self.center);
+        let radius = self.radius as isize;
+        (u - radius..=u + radius, v - radius..=v + radius)
+    }
+
+    /// The locations at exactly `radius` steps, clockwise from the top. The
+    /// boundary of a diamond one larger holds the locations just outside.
+    pub fn boundary(&self) -> impl Iterator<Item = Location> {
+        let radius = self.radius as isize;
+        let Location { x, y } = self.center;
+        // every side walks from one corner up to the next one, a single
+        // location has no sides but is its own boundary
+        let sides = [(0, -1, 1, 1), (1, 0, -1, 1), (0, 1, -1, -1), (-1, 0, 1, -1)];
+        let (count, steps) = match radius {
+            0 => (1, 1),
+            _ => (4, radius),
+        };
+        sides
+            .into_iter()
+            .take(count)
+            .flat_map(move |(corner_x, corner_y, step_x, step_y)| {
+                (0..steps).map(move |step| Location {
+                    x: x + corner_x * radius + step_x * step,
+                    y: y + corner_y * radius + step_y * step,
+                })
+            })
+    }
+}
+
+/// the x values of row y that are covered by any of the diamonds
+pub fn row_coverage(diamonds: &[Diamond], y: isize) -> IntervalSet<isize> {
+    diamonds
+        .iter()
+        .filter_map(|diamond| diamond.row_coverage(y))
+        .collect()
+}
+
+/// A location between `min` and `max` that none of the diamonds cover. When
+/// there is a single one it lies just outside some of the diamonds, so the
+/// corners where their rotated edges cross are tried before scanning row by row.
+pub fn find_uncovered(diamonds: &[Diamond], min: &Location, max: &Location) -> Option<Location> {
+    let is_free = |location: &Location| {
+        (min.x..=max.x).contains(&location.x)
+            && (min.y..=max.y).contains(&location.y)
+            && diamonds.iter().all(|diamond| !diamond.contains(location))
+    };
+    let mut us = vec![];
+    let mut vs = vec![];
+    for diamond in diamonds {
+        let (u, v) = diamond.rotated();
+        us.extend([u.start() - 1, u.end() + 1]);
+        vs.extend([v.start() - 1, v.end() + 1]);
+    }
+    let crossing = us
+        .iter()
+        .flat_map(|u| vs.iter().map(move |v| Rotated { u: *u, v: *v }))
+        .filter_map(|rotated| rotated.to_location())
+        .find(is_free);
+    if crossing.is_some() {
+        return crossing;
+    }
+
+    for y in min.y..=max.y {
+        let row: IntervalSet<isize> = [min.x..=max.x].into_iter().collect();
+        let free = row.difference(&row_coverage(diamonds, y));
+        if let Some(x) = free.bounds().map(|range| *range.start()) {
+            return Some(Location { x, y });
+        }
+    }
+    None
+}
+
+#[test]
+fn diamond_rows_and_boundary() {
+    let diamond = Diamond::through(Location { x: 8, y: 7 }, &Location { x: 2, y: 10 });
+    assert_eq!(diamond.radius, 9);
+    assert_eq!(diamond.row_coverage(7), Some(-1..=17));
+    assert_eq!(diamond.row_coverage(16), Some(8..=8));
+    assert_eq!(diamond.row_coverage(17), None);
+    assert!(diamond.contains(&Location { x: 8, y: -2 }));
+    assert!(!diamond.contains(&Location { x: 8, y: -3 }));
+
+    let boundary: Vec<Location> = diamond.boundary().collect();
+    assert_eq!(boundary.len(), 36);
+    assert!(boundary
+        .iter()
+        .all(|location| location.manhattan_distance(&diamond.center) == 9));
+    assert_eq!(boundary[0], Location { x: 8, y: -2 });
+    let small = Diamond::new(Location { x: 0, y: 0 }, 1);
+    assert_eq!(
+        small.boundary().collect::<Vec<_>>(),
+        vec![
+            Location { x: 0, y: -1 },
+            Location { x: 1, y: 0 },
+            Location { x: 0, y: 1 },
+            Location { x: -1, y: 0 },
+        ]
+    );
+    assert_eq!(
+        Diamond::new(Location { x: 3, y: 4 }, 0).boundary().count(),
+        1
+    );
+}
+
+#[test]
+fn rotated_coordinates() {
+    let location = Location { x: 3, y: -5 };
+    let rotated = Rotated::from(&location);
+    assert_eq!(rotated, Rotated { u: -2, v: 8 });
+    assert_eq!(rotated.to_location(), Some(location));
+    assert_eq!(Rotated { u: 1, v: 0 }.to_location(), None);
+}
+
+#[test]
+fn uncovered_location() {
+    // four corners that leave only (2, 2) free in the 5x5 box
+    let diamonds = vec![
+        Diamond::new(Location { x: 0, y: 0 }, 3),
+        Diamond::new(Location { x: 4, y: 4 }, 3),
+        Diamond::new(Location { x: 4, y: 0 }, 3),
+        Diamond::new(Location { x: 0, y: 4 }, 3),
+    ];
+    let (min, max) = (Location { x: 0, y: 0 }, Location { x: 4, y: 4 });
+    assert_eq!(
+        find_uncovered(&diamonds, &min, &max),
+        Some(Location { x: 2, y: 2 })
+    );
+    assert_eq!(
+        row_coverage(&diamonds, 2).iter().collect::<Vec<_>>(),
+        vec![-1..2, 3..6]
+    );
+    let all = [Diamond::new(Location { x: 2, y: 2 }, 4)];
+    assert_eq!(find_uncovered(&all, &min, &max), None);
+}
@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 pub mod input_engine;
 pub mod grid_engine;
+pub mod manhattan;
 pub mod util_engine;
 pub mod grid;
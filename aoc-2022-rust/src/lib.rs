@@ -1,2 +1,4 @@
 pub mod engine;
 pub mod days;
+
+pub use days::register;
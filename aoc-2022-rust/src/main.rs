@@ -1,12 +1,24 @@
-use days::day_16::main_16::day_16_part_1_run;
-use std::time::SystemTime;
+use aoc_common::registry::Registry;
+use aoc_lib::engine::input_engine::run_day;
+use std::{env, process::ExitCode};
 
-mod days;
-mod engine;
-
-fn main() {
-    let start = SystemTime::now();
-    day_16_part_1_run();
-    let duration_ms = SystemTime::now().duration_since(start);
-    println!("{:?}", duration_ms);
+/// `cargo run -- <day>` runs a registered day on its example and real input
+fn main() -> ExitCode {
+    let mut registry = Registry::new();
+    aoc_lib::register(&mut registry);
+    let puzzle = env::args()
+        .nth(1)
+        .and_then(|day| day.parse().ok())
+        .and_then(|day| registry.get(2022, day));
+    let Some(puzzle) = puzzle else {
+        eprintln!("usage: aoc-2022-rust <day>, with one of the registered days");
+        return ExitCode::FAILURE;
+    };
+    match run_day(puzzle) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,15 @@
+use aoc_2023_rust::days::day_05::Day05;
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
+
+#[allow(dead_code)]
+fn main() {
+    let example_input = include_str!("../../../../src/2023/data/y2023_day05.example");
+    let input = include_str!("../../../../src/2023/data/y2023_day05.input");
+    assert_answer(&Day05, &Variant::Example, Part::One, example_input);
+    let part_1_result = assert_answer(&Day05, &Variant::Real, Part::One, input);
+    println!("part 1 - {}", part_1_result);
+
+    assert_answer(&Day05, &Variant::Example, Part::Two, example_input);
+    let part_2_result = assert_answer(&Day05, &Variant::Real, Part::Two, input);
+    println!("part 2 - {}", part_2_result);
+}
@@ -0,0 +1,77 @@
+use aoc_common::solution::Solution;
+use regex::Regex;
+use substring::Substring;
+
+pub fn part_1(input: &str) -> usize {
+    input
+        .lines()
+        .map(|line| {
+            let digits: Vec<usize> = capture_all_matches(line, &Regex::new("[0-9]").unwrap())
+                .into_iter()
+                .map(|v| usize::from_str_radix(v, 10).unwrap())
+                .collect();
+            let parsed_number = format!("{}{}", digits.first().unwrap(), digits.last().unwrap());
+            usize::from_str_radix(&parsed_number, 10).unwrap()
+        })
+        .reduce(|t, v| t + v)
+        .unwrap()
+}
+
+pub fn part_2(input: &str) -> usize {
+    const WRITTEN_LETTERS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let written_letters = Vec::from(WRITTEN_LETTERS);
+    let raw_regex: String = format!("([0-9]|{})", written_letters.join("|").to_string());
+    let regex: Regex = Regex::new(raw_regex.as_str()).unwrap();
+    input
+        .lines()
+        .map(|line| {
+            let digits: Vec<usize> = capture_all_matches(line, &regex)
+                .into_iter()
+                .map(|v| {
+                    if v.len() == 1 {
+                        return usize::from_str_radix(v, 10).unwrap();
+                    }
+                    return WRITTEN_LETTERS.iter().position(|&x| x == v).unwrap() + 1;
+                })
+                .collect();
+            let parsed_number = format!("{}{}", digits.first().unwrap(), digits.last().unwrap());
+            usize::from_str_radix(&parsed_number, 10).unwrap()
+        })
+        .reduce(|t, v| t + v)
+        .unwrap()
+}
+
+fn capture_all_matches<'a, 'b>(input: &'a str, regex: &'b Regex) -> Vec<&'a str> {
+    let mut output: Vec<&'a str> = vec![];
+    let mut index: usize = 0;
+    let mut digit_match_option = regex.find_at(input, index);
+    while digit_match_option.is_some() {
+        let digit_match = digit_match_option.unwrap();
+        index = digit_match.start() + 1;
+        output.push(input.substring(digit_match.start(), digit_match.end()));
+        digit_match_option = regex.find_at(input, index);
+    }
+    output
+}
+
+pub struct Day01;
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
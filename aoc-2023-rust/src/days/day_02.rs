@@ -0,0 +1,111 @@
+use aoc_common::solution::Solution;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref GAME_REGEX: Regex = Regex::new(r"Game\s+([0-9]+): (.*)$").unwrap();
+}
+enum RGB {
+    Red,
+    Green,
+    Blue,
+}
+struct Game {
+    index: usize,
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+fn get_rgb(rgb: &str) -> RGB {
+    match rgb {
+        "red" => RGB::Red,
+        "green" => RGB::Green,
+        "blue" => RGB::Blue,
+        _ => RGB::Red,
+    }
+}
+
+fn parse_game(line: &str) -> Game {
+    let game_match = GAME_REGEX.captures(line).unwrap();
+    let index = usize::from_str_radix(&game_match[1], 10).unwrap();
+    let mut red_counter: usize = 0;
+    let mut green_counter: usize = 0;
+    let mut blue_counter: usize = 0;
+    game_match[2].split("; ").into_iter().for_each(|set_str| {
+        set_str.split(", ").into_iter().for_each(|pick_str| {
+            let pieces: Vec<&str> = pick_str.split(" ").collect();
+            let amount = usize::from_str_radix(pieces[0], 10).unwrap();
+            let color = get_rgb(pieces[1]);
+            match color {
+                RGB::Red => {
+                    if amount > red_counter {
+                        red_counter = amount;
+                    }
+                }
+                RGB::Green => {
+                    if amount > green_counter {
+                        green_counter = amount;
+                    }
+                }
+                _ => {
+                    if amount > blue_counter {
+                        blue_counter = amount;
+                    }
+                }
+            };
+        });
+    });
+    Game {
+        index,
+        red: red_counter,
+        green: green_counter,
+        blue: blue_counter,
+    }
+}
+
+#[test]
+fn test_parse_game() {
+    let example = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+    let game = parse_game(example);
+    assert_eq!(game.index, 3);
+}
+
+fn is_game_possible(game: &Game) -> bool {
+    return game.red <= 12 && game.green <= 13 && game.blue <= 14;
+}
+
+pub fn part_1(input: &str) -> usize {
+    let games: Vec<Game> = input.lines().map(|l| parse_game(l)).collect();
+    games
+        .into_iter()
+        .filter(is_game_possible)
+        .fold(0, |total, game| total + game.index)
+}
+
+pub fn part_2(input: &str) -> usize {
+    let games: Vec<Game> = input.lines().map(|l| parse_game(l)).collect();
+    games
+        .into_iter()
+        .fold(0, |total, game| total + game.red * game.green * game.blue)
+}
+
+pub struct Day02;
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
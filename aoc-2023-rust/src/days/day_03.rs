@@ -0,0 +1,65 @@
+use std::usize;
+
+use aoc_common::solution::Solution;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref NUMBER_REGEX: Regex = Regex::new(r"[0-9]+").unwrap();
+    static ref SYMBOL_REGEX: Regex = Regex::new(r"[^0-9\.]").unwrap();
+}
+
+pub fn part_1(input: &str) -> usize {
+    let mut total = 0;
+    let lines: Vec<&str> = input.lines().collect();
+    lines.iter().enumerate().for_each(|(line_index, line)| {
+        NUMBER_REGEX
+            .find_iter(line)
+            .map(|mat| (mat.as_str(), mat.start()))
+            .for_each(|(raw_value, index)| {
+                let mut begin_index = 0;
+                if index > 0 {
+                    begin_index = index - 1;
+                }
+                let mut end_index = index + raw_value.len() + 1;
+                if end_index >= line.len() {
+                    end_index = line.len() - 1;
+                }
+                let mut slice: String = line[begin_index..end_index].to_string();
+                if line_index > 0 {
+                    let previous_slice = &lines[line_index - 1][begin_index..end_index];
+                    slice += previous_slice;
+                }
+                if line_index < lines.len() - 1 {
+                    let next_slice = &lines[line_index + 1][begin_index..end_index];
+                    slice += next_slice;
+                }
+                if SYMBOL_REGEX.is_match(slice.as_str()) {
+                    total += usize::from_str_radix(raw_value, 10).unwrap();
+                }
+            });
+    });
+    return total;
+}
+
+pub fn part_2(input: &str) -> usize {
+    0
+}
+
+pub struct Day03;
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = ();
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, _input: &Self::Input<'_>) -> Self::Part2 {}
+}
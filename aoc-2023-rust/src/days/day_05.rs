@@ -0,0 +1,88 @@
+use aoc_common::{
+    interval::{IntervalSet, RangeMap},
+    solution::Solution,
+};
+
+struct Almanac {
+    seeds: Vec<u64>,
+    /// seed to soil, soil to fertilizer, ..., humidity to location
+    stages: Vec<RangeMap<u64>>,
+}
+
+impl Almanac {
+    fn new(input: &str) -> Self {
+        let mut blocks = input.split("\n\n");
+        let seeds = blocks
+            .next()
+            .unwrap()
+            .trim_start_matches("seeds:")
+            .split_whitespace()
+            .map(|seed| seed.parse().unwrap())
+            .collect();
+        let stages = blocks
+            .map(|block| {
+                // the first line only names the stage
+                block.lines().skip(1).fold(RangeMap::new(), |stage, line| {
+                    let numbers: Vec<u64> = line
+                        .split_whitespace()
+                        .map(|number| number.parse().unwrap())
+                        .collect();
+                    let [destination, source, length] = numbers[..] else {
+                        panic!("invalid map line {}", line);
+                    };
+                    stage.with_range(source..source + length, destination)
+                })
+            })
+            .collect();
+        Almanac { seeds, stages }
+    }
+
+    /// all stages in one, from seed to location
+    fn seed_to_location(&self) -> RangeMap<u64> {
+        self.stages
+            .iter()
+            .fold(RangeMap::new(), |combined, stage| combined.then(stage))
+    }
+}
+
+pub fn part_1(input: &str) -> u64 {
+    let almanac = Almanac::new(input);
+    let seed_to_location = almanac.seed_to_location();
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| seed_to_location.apply(*seed))
+        .min()
+        .unwrap()
+}
+
+pub fn part_2(input: &str) -> u64 {
+    let almanac = Almanac::new(input);
+    let seeds: IntervalSet<u64> = almanac
+        .seeds
+        .chunks(2)
+        .map(|pair| pair[0]..pair[0] + pair[1])
+        .collect();
+    let locations = almanac.seed_to_location().apply_set(&seeds);
+    *locations.bounds().unwrap().start()
+}
+
+pub struct Day05;
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
@@ -1 +1,20 @@
-mod day_01;
+use aoc_common::registry::Registry;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_05;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(day_01::Day01);
+    registry.register(day_02::Day02);
+    registry.register(day_03::Day03);
+    registry.register(day_05::Day05);
+}
+
+#[test]
+fn register_days() {
+    let mut registry = Registry::new();
+    register(&mut registry);
+    assert_eq!(registry.iter_year(2023).count(), 4);
+}
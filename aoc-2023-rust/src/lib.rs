@@ -0,0 +1,3 @@
+pub mod days;
+
+pub use days::register;
@@ -0,0 +1,86 @@
+use aoc_common::{grid::GridLoader, solution::Solution};
+
+use crate::map::{Cell, DenseMap2d};
+
+#[derive(PartialEq)]
+enum CellType {
+    Empty,
+    PaperRoll,
+}
+
+impl CellType {
+    pub fn from_char(input: char) -> Self {
+        match input {
+            '@' => CellType::PaperRoll,
+            _ => CellType::Empty,
+        }
+    }
+}
+
+type PaperRollDiagram = DenseMap2d<CellType>;
+impl PaperRollDiagram {
+    pub fn from_input(input: &str) -> Self {
+        GridLoader::new()
+            .load(input, |c| Some(CellType::from_char(c)))
+            .unwrap()
+            .grid
+    }
+    pub fn get_eligible_paper_rolls(&self) -> Vec<&Cell<CellType>> {
+        self.iter_all()
+            .filter(|c| {
+                c.value == CellType::PaperRoll
+                    && self
+                        .get_adjacent_values(c.x, c.y)
+                        .iter()
+                        .filter(|c| c.value == CellType::PaperRoll)
+                        .count()
+                        < 4
+            })
+            .collect()
+    }
+}
+
+pub fn execute_part1(input: &str) -> usize {
+    PaperRollDiagram::from_input(input)
+        .get_eligible_paper_rolls()
+        .len()
+}
+
+pub fn execute_part2(input: &str) -> u64 {
+    let mut diagram = PaperRollDiagram::from_input(input);
+    let mut removed_counter = 0;
+    let mut has_moved = true;
+    while has_moved {
+        let rolls_to_move: Vec<_> = diagram
+            .get_eligible_paper_rolls()
+            .iter()
+            .map(|roll| (roll.x, roll.y))
+            .collect();
+        has_moved = !rolls_to_move.is_empty();
+        for (x, y) in rolls_to_move {
+            diagram.set_value(x, y, CellType::Empty);
+            removed_counter += 1;
+        }
+    }
+    removed_counter
+}
+
+pub struct Day04;
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        execute_part1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        execute_part2(input)
+    }
+}
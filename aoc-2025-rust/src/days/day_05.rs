@@ -0,0 +1,58 @@
+use aoc_common::{interval::IntervalSet, solution::Solution};
+use regex::Regex;
+
+fn parse_range(line: &str) -> (u64, u64) {
+    let re = Regex::new(r"(\d+)-(\d+)").unwrap();
+    let captures = re.captures(line).unwrap();
+    (captures[1].parse().unwrap(), captures[2].parse().unwrap())
+}
+
+fn parse_input(input: &str) -> (IntervalSet<u64>, Vec<u64>) {
+    let mut fresh = IntervalSet::new();
+    let mut ingredients = vec![];
+
+    let mut has_passed_ranges = false;
+    input.lines().for_each(|line| {
+        if has_passed_ranges {
+            return ingredients.push(line.parse::<u64>().unwrap());
+        };
+        if line.is_empty() {
+            has_passed_ranges = true;
+            return;
+        }
+        let (min, max) = parse_range(line);
+        fresh.insert(min..=max);
+    });
+
+    (fresh, ingredients)
+}
+
+pub fn execute_part1(input: &str) -> usize {
+    let (fresh, ingredients) = parse_input(input);
+    ingredients.iter().filter(|id| fresh.contains(**id)).count()
+}
+
+pub fn execute_part2(input: &str) -> u64 {
+    let (fresh, _) = parse_input(input);
+    fresh.len()
+}
+
+pub struct Day05;
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        execute_part1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        execute_part2(input)
+    }
+}
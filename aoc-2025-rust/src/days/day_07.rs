@@ -0,0 +1,193 @@
+use aoc_common::{
+    grid::{FromChar, GridLoader, Loaded},
+    solution::Solution,
+};
+
+use crate::map::{Map2d, Position};
+
+#[derive(PartialEq)]
+pub enum Tachyon {
+    Start,
+    Empty,
+    Splitter,
+    Beam,
+}
+
+struct Value {
+    v_type: Tachyon,
+    has_split: bool,
+    paths: Option<usize>,
+}
+
+impl FromChar for Tachyon {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'S' => Some(Tachyon::Start),
+            '.' => Some(Tachyon::Empty),
+            '^' => Some(Tachyon::Splitter),
+            _ => None,
+        }
+    }
+}
+
+impl Tachyon {
+    #[allow(dead_code)]
+    fn to_string(&self) -> char {
+        match self {
+            Tachyon::Start => 'S',
+            Tachyon::Empty => '.',
+            Tachyon::Splitter => '^',
+            Tachyon::Beam => '|',
+        }
+    }
+}
+
+struct TachyonMap {
+    map: Map2d<Value>,
+    start_x: i32,
+    start_y: i32,
+    split_counter: usize,
+    timeline_counter: usize,
+}
+
+impl TachyonMap {
+    fn cast_ray(&mut self, start: Position) -> bool {
+        if !self.map.has_value(start.x, start.y) {
+            return false;
+        }
+        {
+            let current = self.map.get_value_mut(start.x, start.y);
+            if current.value.v_type == Tachyon::Beam {
+                return false;
+            }
+            if current.value.v_type != Tachyon::Start {
+                current.value.v_type = Tachyon::Beam;
+            }
+        }
+
+        let x = start.x;
+        let mut y = start.y + 1;
+        let mut next = self.map.try_get_value(x, y);
+        while let Some(cell) = next {
+            if cell.value.v_type == Tachyon::Splitter {
+                if cell.value.has_split {
+                    return false;
+                }
+                self.map.get_value_mut(x, y).value.has_split = true;
+                self.split_counter += 1;
+                self.cast_ray(Position { x: x - 1, y });
+                self.cast_ray(Position { x: x + 1, y });
+                break;
+            } else if cell.value.v_type == Tachyon::Empty {
+                self.map.get_value_mut(x, y).value.v_type = Tachyon::Beam;
+            }
+            y += 1;
+            next = self.map.try_get_value(x, y);
+        }
+        true
+    }
+
+    fn explore_rays(&mut self, start: Position) -> usize {
+        if !self.map.has_value(start.x, start.y) {
+            return 0;
+        }
+        {
+            let current = self.map.get_value(start.x, start.y);
+            if let Some(paths) = current.value.paths {
+                return paths;
+            }
+        }
+
+        let x = start.x;
+        let mut y = start.y + 1;
+        let mut next = self.map.try_get_value(x, y);
+        let mut total = 1;
+        while let Some(cell) = next {
+            if cell.value.v_type == Tachyon::Splitter {
+                self.timeline_counter += 1;
+                total = self.explore_rays(Position { x: x - 1, y });
+                total += self.explore_rays(Position { x: x + 1, y });
+                break;
+            }
+            y += 1;
+            next = self.map.try_get_value(x, y);
+        }
+        self.map.get_value_mut(start.x, start.y).value.paths = Some(total);
+        total
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        for y in self.map.boundaries.min_y..=self.map.boundaries.max_y {
+            let mut line: String = "".to_string();
+            for x in self.map.boundaries.min_x..=self.map.boundaries.max_x {
+                line = format!(
+                    "{} {}",
+                    line,
+                    self.map.get_value(x, y).value.v_type.to_string()
+                );
+            }
+            println!("{line}");
+        }
+    }
+}
+
+fn parse_input(input: &str) -> TachyonMap {
+    let loaded: Loaded<Map2d<Value>> = GridLoader::new()
+        .with_markers(&['S'])
+        .load(input, |c| {
+            Some(Value {
+                v_type: Tachyon::from_char(c)?,
+                has_split: false,
+                paths: None,
+            })
+        })
+        .unwrap();
+    let start: Position = loaded.marker('S').unwrap().into();
+    TachyonMap {
+        map: loaded.grid,
+        start_x: start.x,
+        start_y: start.y,
+        split_counter: 0,
+        timeline_counter: 0,
+    }
+}
+
+pub fn execute_part1(input: &str) -> usize {
+    let mut map = parse_input(input);
+    map.cast_ray(Position {
+        x: map.start_x,
+        y: map.start_y,
+    });
+    // map.print();
+
+    map.split_counter
+}
+
+pub fn execute_part2(input: &str) -> usize {
+    let mut map = parse_input(input);
+    map.explore_rays(Position {
+        x: map.start_x,
+        y: map.start_y,
+    })
+}
+
+pub struct Day07;
+impl Solution for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        execute_part1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        execute_part2(input)
+    }
+}
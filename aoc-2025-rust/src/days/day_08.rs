@@ -0,0 +1,102 @@
+use aoc_common::{
+    graph::{UnionFind, kruskal},
+    input::Variant,
+    solution::Solution,
+};
+
+pub struct JunctionBox {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+impl JunctionBox {
+    fn new(line: &str) -> Self {
+        let pieces: Vec<&str> = line.split(",").collect();
+        assert_eq!(pieces.len(), 3);
+
+        JunctionBox {
+            x: pieces[0].parse().unwrap(),
+            y: pieces[1].parse().unwrap(),
+            z: pieces[2].parse().unwrap(),
+        }
+    }
+
+    /// squared, the order of the distances is all that matters
+    fn distance(&self, other: &JunctionBox) -> usize {
+        let dx = self.x.abs_diff(other.x);
+        let dy = self.y.abs_diff(other.y);
+        let dz = self.z.abs_diff(other.z);
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+/// every pair of boxes by index with their distance, closest first
+fn get_distances(boxes: &[JunctionBox]) -> Vec<(usize, usize, usize)> {
+    let mut distances = vec![];
+    for (a, from) in boxes.iter().enumerate() {
+        for (b, other) in boxes.iter().enumerate().skip(a + 1) {
+            distances.push((a, b, from.distance(other)));
+        }
+    }
+    distances.sort_by_key(|(_, _, distance)| *distance);
+    distances
+}
+
+pub fn execute_part1(input: &str, connections: usize) -> usize {
+    let boxes: Vec<JunctionBox> = input.lines().map(JunctionBox::new).collect();
+    let mut circuits = UnionFind::new(boxes.len());
+    for (a, b, _) in get_distances(&boxes).into_iter().take(connections) {
+        circuits.union(a, b);
+    }
+
+    let mut sizes = circuits.component_sizes();
+    sizes.sort_by_key(|size| std::cmp::Reverse(*size));
+    sizes.iter().take(3).product()
+}
+
+pub fn execute_part2(input: &str) -> usize {
+    let boxes: Vec<JunctionBox> = input.lines().map(JunctionBox::new).collect();
+    let (a, b, _) = kruskal(boxes.len(), get_distances(&boxes))
+        .last()
+        .expect("at least two boxes");
+    boxes[a].x * boxes[b].x
+}
+
+/// the boxes, with the number of closest pairs to connect in part 1
+pub struct Playground<'a> {
+    input: &'a str,
+    connections: usize,
+}
+
+pub struct Day08;
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Playground<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        Playground {
+            input,
+            connections: 1000,
+        }
+    }
+    /// the example connects the 10 closest boxes, the real input the 1000 closest
+    fn parse_variant<'a>(&self, input: &'a str, variant: &Variant) -> Self::Input<'a> {
+        match variant {
+            Variant::Example => Playground {
+                input,
+                connections: 10,
+            },
+            _ => self.parse(input),
+        }
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        execute_part1(input.input, input.connections)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        execute_part2(input.input)
+    }
+}
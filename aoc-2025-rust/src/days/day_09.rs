@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    geometry::{CompressedPolygon, Polygon},
+    grid::{Color, Position as GridPosition, Style, TerminalRenderer},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl PartialEq for Position {
+    fn eq(&self, other: &Position) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl Position {
+    pub fn new(line: &str) -> Self {
+        let pieces: Vec<&str> = line.split(",").collect();
+        Position {
+            x: pieces.first().unwrap().parse().unwrap(),
+            y: pieces.get(1).unwrap().parse().unwrap(),
+        }
+    }
+    pub fn calculate_size(&self, other: &Position) -> usize {
+        let x_size = std::cmp::max(self.x, other.x) - std::cmp::min(self.x, other.x) + 1;
+        let y_size = std::cmp::max(self.y, other.y) - std::cmp::min(self.y, other.y) + 1;
+
+        x_size * y_size
+    }
+    fn to_grid(&self) -> GridPosition {
+        GridPosition::new(self.x as isize, self.y as isize)
+    }
+    fn origin_distance(&self) -> f64 {
+        let dx = self.x as f64 - 0_f64;
+        let dy = self.y as f64 - 0_f64;
+        (dx * dx + dy * dy).sqrt()
+    }
+}
+#[test]
+fn test_caluculate_size() {
+    let a = Position::new("2,5");
+    let b = Position::new("9,7");
+    assert_eq!(a.calculate_size(&b), 24);
+}
+
+#[allow(unused)]
+pub fn execute_part1(input: &str) -> usize {
+    let mut positions: Vec<Position> = input.lines().map(Position::new).collect();
+    positions.sort_by(|a, b| a.origin_distance().total_cmp(&b.origin_distance()));
+    let start = positions.first().unwrap();
+    let end = positions.last().unwrap();
+    start.calculate_size(end)
+}
+
+/// the compressed floor, red tiles and the corners of the rectangle on top
+fn print_floor(floor: &CompressedPolygon, positions: &[Position], best: &[Position]) {
+    let compressed = |position: &Position| {
+        let x = floor.xs().index(position.x as isize).unwrap();
+        let y = floor.ys().index(position.y as isize).unwrap();
+        GridPosition::new(x as isize, y as isize)
+    };
+    TerminalRenderer::new()
+        .with_highlight(
+            positions.iter().map(compressed),
+            Style::new('#').with_foreground(Color::RED),
+        )
+        .with_highlight(
+            best.iter().map(compressed),
+            Style::new('O').with_foreground(Color::YELLOW).with_bold(),
+        )
+        .print(floor.covered(), |cell| match cell {
+            Some(true) => Style::new('X').with_foreground(Color::GREEN),
+            _ => Style::new('.'),
+        });
+}
+
+pub fn execute_part2(input: &str, print: bool) -> usize {
+    let positions: Vec<Position> = input.lines().map(Position::new).collect();
+    let floor = Polygon::new(positions.iter().map(Position::to_grid))
+        .compress()
+        .expect("the red tiles are connected by straight lines");
+
+    let mut best: Option<(usize, &Position, &Position)> = None;
+    for (i, start) in positions.iter().enumerate() {
+        for other in positions.iter().skip(i + 1) {
+            let size = start.calculate_size(other);
+            if best.is_some_and(|(largest, _, _)| size <= largest) {
+                continue;
+            }
+            if floor.contains_rectangle(start.to_grid(), other.to_grid()) {
+                best = Some((size, start, other));
+            }
+        }
+    }
+    let (largest, start, other) = best.expect("a single red tile is a rectangle");
+    if print {
+        print_floor(&floor, &positions, &[start.clone(), other.clone()]);
+    }
+    largest
+}
+
+pub struct Day09;
+impl Solution for Day09 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        execute_part1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        execute_part2(input, false)
+    }
+}
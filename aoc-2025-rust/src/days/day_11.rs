@@ -0,0 +1,61 @@
+use aoc_common::{graph::Graph, solution::Solution};
+
+const YOU: &str = "you";
+const OUT: &str = "out";
+const SVR: &str = "svr";
+const FFT: &str = "fft";
+const DAC: &str = "dac";
+
+struct ServerRack {
+    graph: Graph,
+}
+
+impl ServerRack {
+    fn new(input: &str) -> Self {
+        ServerRack {
+            graph: Graph::from_adjacency(input, ":"),
+        }
+    }
+
+    fn count_paths(&self, from: &str, to: &str) -> usize {
+        let (Some(from), Some(to)) = (self.graph.id(from), self.graph.id(to)) else {
+            return 0;
+        };
+        self.graph
+            .count_paths(from, to)
+            .expect("the server racks to not form a cycle") as usize
+    }
+}
+
+#[allow(unused)]
+pub fn execute_part1(input: &str) -> usize {
+    ServerRack::new(input).count_paths(YOU, OUT)
+}
+
+#[allow(unused)]
+pub fn execute_part2(input: &str) -> usize {
+    let rack = ServerRack::new(input);
+    // without cycles only one of fft and dac can come first
+    rack.count_paths(SVR, FFT) * rack.count_paths(FFT, DAC) * rack.count_paths(DAC, OUT)
+        + rack.count_paths(SVR, DAC) * rack.count_paths(DAC, FFT) * rack.count_paths(FFT, OUT)
+}
+
+pub struct Day11;
+impl Solution for Day11 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 11;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        execute_part1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        execute_part2(input)
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_common::{
+    cover::{Orientations, Packing, Shape},
+    solution::Solution,
+};
+
+/// the presents all fit in a 3x3 box
+const PRESENT_SIZE: usize = 3;
+
+struct PresentArea {
+    width: usize,
+    height: usize,
+    /// the index is equal to the present id
+    required_presents: Vec<usize>,
+}
+impl PresentArea {
+    fn new(line: &str) -> Self {
+        let (dimension_str, present_str) = line.split_once(": ").expect("is valid dimension");
+        let (width_str, height_str) = dimension_str.split_once("x").expect("valid dimension part");
+        PresentArea {
+            width: width_str.parse().expect("valid width"),
+            height: height_str.parse().expect("valid height"),
+            required_presents: present_str
+                .split(" ")
+                .map(|x| x.parse().expect("valid required present"))
+                .collect(),
+        }
+    }
+
+    fn can_place_all_presents(&self, presents: &[Shape]) -> bool {
+        // every present gets a box of its own, no need to search
+        let boxes = (self.width / PRESENT_SIZE) * (self.height / PRESENT_SIZE);
+        if self.required_presents.iter().sum::<usize>() <= boxes {
+            return true;
+        }
+        presents
+            .iter()
+            .zip(self.required_presents.iter())
+            .fold(
+                Packing::rectangle(self.width, self.height),
+                |packing, (present, count)| {
+                    packing.with_piece(present.clone(), *count, Orientations::All)
+                },
+            )
+            .fits()
+    }
+}
+
+pub fn execute_part1(input: &str) -> usize {
+    let mut presents: Vec<Shape> = vec![];
+    let mut cached_lines: Vec<&str> = vec![];
+    for line in input.lines() {
+        if line.is_empty() {
+            if !cached_lines.is_empty() {
+                assert!(cached_lines.len() == 4);
+                presents.push(Shape::parse(&cached_lines[1..].join("\n")));
+            }
+            cached_lines = vec![];
+        } else {
+            cached_lines.push(line);
+        }
+    }
+    cached_lines
+        .into_iter()
+        .map(PresentArea::new)
+        .filter(|area| area.can_place_all_presents(&presents))
+        .count()
+}
+
+pub struct Day12;
+impl Solution for Day12 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 12;
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = ();
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        execute_part1(input)
+    }
+    fn part_2(&self, _input: &Self::Input<'_>) -> Self::Part2 {}
+}
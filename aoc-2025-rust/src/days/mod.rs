@@ -0,0 +1,36 @@
+use aoc_common::registry::Registry;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(day_01::Day01);
+    registry.register(day_02::Day02);
+    registry.register(day_03::Day03);
+    registry.register(day_04::Day04);
+    registry.register(day_05::Day05);
+    registry.register(day_06::Day06);
+    registry.register(day_07::Day07);
+    registry.register(day_08::Day08);
+    registry.register(day_09::Day09);
+    registry.register(day_10::Day10);
+    registry.register(day_11::Day11);
+    registry.register(day_12::Day12);
+}
+
+#[test]
+fn register_days() {
+    let mut registry = Registry::new();
+    register(&mut registry);
+    assert_eq!(registry.iter_year(2025).count(), 12);
+}
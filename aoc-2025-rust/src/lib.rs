@@ -1 +1,4 @@
+pub mod days;
 pub mod map;
+
+pub use days::register;
@@ -1,10 +1,25 @@
-use std::collections::HashMap;
+use aoc_common::grid::{self as shared, DenseGrid, FromCells, Grid, SparseGrid};
 
 pub struct Position {
     pub x: i32,
     pub y: i32,
 }
 
+impl From<Position> for shared::Position {
+    fn from(position: Position) -> Self {
+        shared::Position::new(position.x as isize, position.y as isize)
+    }
+}
+
+impl From<shared::Position> for Position {
+    fn from(position: shared::Position) -> Self {
+        Position {
+            x: position.x as i32,
+            y: position.y as i32,
+        }
+    }
+}
+
 pub struct Cell<V> {
     pub x: i32,
     pub y: i32,
@@ -18,6 +33,12 @@ pub struct Boundaries {
     pub max_y: i32,
 }
 
+impl Default for Boundaries {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Boundaries {
     pub fn new() -> Self {
         Boundaries {
@@ -43,12 +64,35 @@ impl Boundaries {
     }
 }
 
+fn adjacent_values<'a, V>(
+    x: i32,
+    y: i32,
+    try_get_value: impl Fn(i32, i32) -> Option<&'a Cell<V>>,
+) -> Vec<&'a Cell<V>> {
+    let mut output = vec![];
+    for y_offset in -1..=1 {
+        for x_offset in -1..=1 {
+            if x_offset == 0 && y_offset == 0 {
+                continue;
+            }
+            if let Some(cell) = try_get_value(x + x_offset, y + y_offset) {
+                output.push(cell);
+            }
+        }
+    }
+    output
+}
+
+/// Keeps the original i32 api, the cells are stored in the shared `SparseGrid`.
 pub struct Map2d<V> {
-    /// [y][x]
-    values: HashMap<i32, HashMap<i32, Cell<V>>>,
+    values: SparseGrid<Cell<V>>,
     pub boundaries: Boundaries,
 }
 
+fn key(x: i32, y: i32) -> shared::Position {
+    shared::Position::new(x as isize, y as isize)
+}
+
 impl<V> Default for Map2d<V> {
     fn default() -> Self {
         Self::new()
@@ -58,48 +102,144 @@ impl<V> Default for Map2d<V> {
 impl<V> Map2d<V> {
     pub fn new() -> Self {
         Map2d {
-            values: HashMap::new(),
+            values: SparseGrid::new(),
             boundaries: Boundaries::new(),
         }
     }
     pub fn set_value(&mut self, x: i32, y: i32, v: V) {
         self.boundaries.update_boundaries(x, y);
-        self.values
-            .entry(y)
-            .or_default()
-            .insert(x, Cell { x, y, value: v });
+        self.values.set(key(x, y), Cell { x, y, value: v });
     }
     pub fn get_value(&self, x: i32, y: i32) -> &Cell<V> {
-        self.values.get(&y).unwrap().get(&x).unwrap()
+        self.values.get(key(x, y)).unwrap()
     }
     pub fn try_get_value(&self, x: i32, y: i32) -> Option<&Cell<V>> {
-        if let Some(y_map) = self.values.get(&y) {
-            return y_map.get(&x);
-        }
-        None
+        self.values.get(key(x, y))
     }
     pub fn has_value(&self, x: i32, y: i32) -> bool {
-        if let Some(y_map) = self.values.get(&y) {
-            return y_map.contains_key(&x);
-        }
-        false
+        self.values.contains(key(x, y))
     }
     pub fn get_value_mut(&mut self, x: i32, y: i32) -> &mut Cell<V> {
-        self.values.get_mut(&y).unwrap().get_mut(&x).unwrap()
+        self.values.get_mut(key(x, y)).unwrap()
     }
     pub fn get_adjacent_values(&self, x: i32, y: i32) -> Vec<&Cell<V>> {
-        let mut output = vec![];
-        for y_offset in -1..=1 {
-            for x_offset in -1..=1 {
-                if x_offset == 0 && y_offset == 0 {
-                    continue;
-                }
-                if let Some(cell) = self.try_get_value(x + x_offset, y + y_offset) {
-                    output.push(cell);
-                }
+        adjacent_values(x, y, |x, y| self.try_get_value(x, y))
+    }
+    pub fn iter_all(&self) -> impl Iterator<Item = &Cell<V>> {
+        let b = &self.boundaries;
+        (b.min_y..=b.max_y)
+            .flat_map(move |y| (b.min_x..=b.max_x).filter_map(move |x| self.try_get_value(x, y)))
+    }
+}
+
+impl<V> Grid<V> for Map2d<V> {
+    fn boundaries(&self) -> shared::Boundaries {
+        self.values.boundaries
+    }
+
+    fn get(&self, position: shared::Position) -> Option<&V> {
+        self.values.get(position).map(|cell| &cell.value)
+    }
+
+    fn get_mut(&mut self, position: shared::Position) -> Option<&mut V> {
+        self.values.get_mut(position).map(|cell| &mut cell.value)
+    }
+
+    fn set(&mut self, position: shared::Position, value: V) {
+        let Position { x, y } = position.into();
+        self.set_value(x, y, value);
+    }
+}
+
+impl<V> FromCells<V> for Map2d<V> {
+    fn from_cells(_: shared::Boundaries, cells: Vec<(shared::Position, V)>) -> Option<Self> {
+        let mut map = Map2d::new();
+        for (position, value) in cells {
+            map.set(position, value);
+        }
+        Some(map)
+    }
+}
+
+/// The `Map2d` api on a contiguous `DenseGrid`, for maps where nearly every cell is set.
+/// Setting a value outside the allocated area grows it, doubling in that direction.
+pub struct DenseMap2d<V> {
+    values: DenseGrid<Option<Cell<V>>>,
+    pub boundaries: Boundaries,
+}
+
+impl<V> Default for DenseMap2d<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> DenseMap2d<V> {
+    pub fn new() -> Self {
+        Self::with_size(0, 0)
+    }
+    /// allocates the cells of (0,0) up to (width - 1, height - 1)
+    pub fn with_size(width: usize, height: usize) -> Self {
+        DenseMap2d {
+            values: DenseGrid::from_fn(
+                shared::Boundaries {
+                    min_x: 0,
+                    max_x: width as isize - 1,
+                    min_y: 0,
+                    max_y: height as isize - 1,
+                },
+                |_| None,
+            ),
+            boundaries: Boundaries::new(),
+        }
+    }
+    fn grow(&mut self, x: i32, y: i32) {
+        let position = key(x, y);
+        let mut allocated = match self.values.is_empty() {
+            true => shared::Boundaries::from_position(position),
+            false => self.values.boundaries(),
+        };
+        let (width, height) = (self.values.width() as isize, self.values.height() as isize);
+        if position.x < allocated.min_x {
+            allocated.min_x = position.x - width;
+        }
+        if position.x > allocated.max_x {
+            allocated.max_x = position.x + width;
+        }
+        if position.y < allocated.min_y {
+            allocated.min_y = position.y - height;
+        }
+        if position.y > allocated.max_y {
+            allocated.max_y = position.y + height;
+        }
+        let old = std::mem::replace(&mut self.values, DenseGrid::from_fn(allocated, |_| None));
+        for (position, cell) in old.into_cells() {
+            if cell.is_some() {
+                self.values[position] = cell;
             }
         }
-        output
+    }
+    pub fn set_value(&mut self, x: i32, y: i32, v: V) {
+        if self.values.index_of(key(x, y)).is_none() {
+            self.grow(x, y);
+        }
+        self.boundaries.update_boundaries(x, y);
+        self.values[key(x, y)] = Some(Cell { x, y, value: v });
+    }
+    pub fn get_value(&self, x: i32, y: i32) -> &Cell<V> {
+        self.try_get_value(x, y).unwrap()
+    }
+    pub fn try_get_value(&self, x: i32, y: i32) -> Option<&Cell<V>> {
+        self.values.get(key(x, y))?.as_ref()
+    }
+    pub fn has_value(&self, x: i32, y: i32) -> bool {
+        self.try_get_value(x, y).is_some()
+    }
+    pub fn get_value_mut(&mut self, x: i32, y: i32) -> &mut Cell<V> {
+        self.values.get_mut(key(x, y)).unwrap().as_mut().unwrap()
+    }
+    pub fn get_adjacent_values(&self, x: i32, y: i32) -> Vec<&Cell<V>> {
+        adjacent_values(x, y, |x, y| self.try_get_value(x, y))
     }
     pub fn iter_all(&self) -> impl Iterator<Item = &Cell<V>> {
         let b = &self.boundaries;
@@ -107,3 +247,68 @@ impl<V> Map2d<V> {
             .flat_map(move |y| (b.min_x..=b.max_x).filter_map(move |x| self.try_get_value(x, y)))
     }
 }
+
+impl<V> Grid<V> for DenseMap2d<V> {
+    fn boundaries(&self) -> shared::Boundaries {
+        let Boundaries {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        } = self.boundaries;
+        shared::Boundaries {
+            min_x: min_x as isize,
+            max_x: max_x as isize,
+            min_y: min_y as isize,
+            max_y: max_y as isize,
+        }
+    }
+
+    fn get(&self, position: shared::Position) -> Option<&V> {
+        let cell = self.values.get(position)?.as_ref()?;
+        Some(&cell.value)
+    }
+
+    fn get_mut(&mut self, position: shared::Position) -> Option<&mut V> {
+        let cell = self.values.get_mut(position)?.as_mut()?;
+        Some(&mut cell.value)
+    }
+
+    fn set(&mut self, position: shared::Position, value: V) {
+        let Position { x, y } = position.into();
+        self.set_value(x, y, value);
+    }
+}
+
+impl<V> FromCells<V> for DenseMap2d<V> {
+    /// allocates the boundaries up front so loading never grows the map
+    fn from_cells(
+        boundaries: shared::Boundaries,
+        cells: Vec<(shared::Position, V)>,
+    ) -> Option<Self> {
+        let mut map = DenseMap2d {
+            values: DenseGrid::from_fn(boundaries, |_| None),
+            boundaries: Boundaries::new(),
+        };
+        for (position, value) in cells {
+            map.set(position, value);
+        }
+        Some(map)
+    }
+}
+
+#[test]
+fn dense_map_grows() {
+    let mut map = DenseMap2d::new();
+    for (x, y) in [(0, 0), (3, 0), (1, 2), (-2, -1)] {
+        map.set_value(x, y, x * 10 + y);
+    }
+    assert_eq!(map.get_value(1, 2).value, 12);
+    assert_eq!(map.try_get_value(1, 1).map(|c| c.value), None);
+    assert!(map.has_value(-2, -1));
+    map.get_value_mut(3, 0).value = 7;
+    assert_eq!(map.get_adjacent_values(2, 1).len(), 2);
+    let values: Vec<i32> = map.iter_all().map(|c| c.value).collect();
+    assert_eq!(values, vec![-21, 0, 7, 12]);
+    assert_eq!(Grid::boundaries(&map).width(), 6);
+}
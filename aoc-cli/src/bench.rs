@@ -0,0 +1,105 @@
+use std::{path::PathBuf, process::ExitCode, time::Duration};
+
+use aoc_common::{
+    bench::{DayBench, Report},
+    input::{InputError, InputResolver, Variant},
+    registry::Registry,
+    solution::Part,
+};
+
+pub struct BenchOptions {
+    pub day: Option<u8>,
+    pub runs: usize,
+    pub variant: Variant,
+    pub output: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    /// in percent
+    pub threshold: f64,
+}
+
+fn format(nanos: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos))
+}
+
+/// Times every registered day of the year, or a single day, and compares with a baseline report.
+pub fn bench(
+    registry: &Registry,
+    resolver: &InputResolver,
+    year: u16,
+    options: &BenchOptions,
+) -> Result<ExitCode, String> {
+    let baseline = match &options.baseline {
+        Some(path) => Some(Report::load(path).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let puzzles: Vec<_> = registry
+        .iter_year(year)
+        .filter(|puzzle| options.day.is_none_or(|day| day == puzzle.day()))
+        .collect();
+    if puzzles.is_empty() {
+        return Err(format!("no registered days to benchmark for {}", year));
+    }
+
+    let mut report = Report {
+        runs: options.runs,
+        days: vec![],
+    };
+    println!(
+        "{:<4}  {:>3}  {:<7}  {:>10}  {:>10}  {:>10}  {:>10}",
+        year, "day", "", "min", "median", "mean", "stddev"
+    );
+    for puzzle in puzzles {
+        let input = match resolver.read(year, puzzle.day(), &options.variant) {
+            Ok(input) => input,
+            Err(InputError::NotFound { .. }) => {
+                // a day without input is skipped, like verify does
+                println!("{:<4}  {:>3}  {:<7}", "", puzzle.day(), "no input");
+                continue;
+            }
+            Err(error) => return Err(error.to_string()),
+        };
+        let bench = DayBench::measure(puzzle, &input, &options.variant, &Part::ALL, options.runs);
+        for (name, stats) in bench.timings() {
+            println!(
+                "{:<4}  {:>3}  {:<7}  {:>10}  {:>10}  {:>10}  {:>10}",
+                "",
+                bench.day,
+                name,
+                format(stats.min),
+                format(stats.median),
+                format(stats.mean),
+                format(stats.stddev)
+            );
+        }
+        report.days.push(bench);
+    }
+
+    if let Some(path) = &options.output {
+        report.save(path).map_err(|e| e.to_string())?;
+        println!("report written to {}", path.display());
+    }
+
+    let mut code = ExitCode::SUCCESS;
+    if let Some(baseline) = baseline {
+        println!("compared to the baseline medians:");
+        for comparison in report.compare(&baseline, options.threshold / 100.0) {
+            println!(
+                "{:>3}  {:<7}  {:>10} -> {:>10}  {:>+7.1}%{}",
+                comparison.day,
+                comparison.name,
+                format(comparison.baseline),
+                format(comparison.current),
+                comparison.change * 100.0,
+                if comparison.regression {
+                    "  regression"
+                } else {
+                    ""
+                }
+            );
+            if comparison.regression {
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    Ok(code)
+}
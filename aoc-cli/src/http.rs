@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+use aoc_common::download::{Http, HttpError};
+use ureq::Agent;
+
+const USER_AGENT: &str = "github.com/hammerlink/adventofcode aoc-cli";
+
+pub struct UreqHttp {
+    agent: Agent,
+}
+
+impl Default for UreqHttp {
+    fn default() -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        UreqHttp { agent }
+    }
+}
+
+fn read_response(
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String, HttpError> {
+    let mut response = response.map_err(|e| HttpError::Transport(e.to_string()))?;
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| HttpError::Transport(e.to_string()))?;
+    match status {
+        200..=299 => Ok(body),
+        _ => Err(HttpError::Status { status, body }),
+    }
+}
+
+impl Http for UreqHttp {
+    fn get(&self, url: &str, session: &str) -> Result<String, HttpError> {
+        read_response(
+            self.agent
+                .get(url)
+                .header("Cookie", format!("session={}", session))
+                .call(),
+        )
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, HttpError> {
+        read_response(
+            self.agent
+                .post(url)
+                .header("Cookie", format!("session={}", session))
+                .send_form(form.iter().copied()),
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod stand_in {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    /// A request as the stand-in server received it.
+    pub struct Received {
+        pub request_line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    /// Serves the given (status, body) responses in order on a local port,
+    /// returns the base url and the requests it received.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<Received>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut headers = vec![];
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end().to_string();
+                    if line.is_empty() {
+                        break;
+                    }
+                    headers.push(line);
+                }
+                let length = headers
+                    .iter()
+                    .find_map(|h| {
+                        h.to_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|l| l.trim().parse().unwrap())
+                    })
+                    .unwrap_or(0);
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 {} X\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+                sender
+                    .send(Received {
+                        request_line: request_line.trim_end().to_string(),
+                        headers,
+                        body: String::from_utf8(request_body).unwrap(),
+                    })
+                    .unwrap();
+            }
+        });
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, time::Duration};
+
+    use aoc_common::{
+        download::{DownloadError, Downloader, Throttle},
+        input::InputResolver,
+        solution::{Answer, Part},
+        submit::{GuessHistory, Submitter, Verdict},
+    };
+
+    use super::*;
+
+    #[test]
+    fn download_from_stand_in_server() {
+        let (url, received) = stand_in::serve(vec![(200, "1721\n979\n"), (400, "expired")]);
+        let dir = env::temp_dir().join(format!("aoc-cli-download-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let downloader = |session| {
+            Downloader::new(UreqHttp::default(), InputResolver::new(&dir), session)
+                .with_base_url(&url)
+                .with_throttle(Throttle::new(Duration::ZERO))
+        };
+
+        let path = downloader("secret").fetch(2020, 1).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1721\n979\n");
+        let request = received.recv().unwrap();
+        assert_eq!(request.request_line, "GET /2020/day/1/input HTTP/1.1");
+        assert!(
+            request
+                .headers
+                .iter()
+                .any(|h| h == "cookie: session=secret")
+        );
+
+        // cached, the server is not asked again
+        assert_eq!(downloader("secret").fetch(2020, 1).unwrap(), path);
+        assert!(matches!(
+            downloader("old").fetch(2020, 2),
+            Err(DownloadError::Http(HttpError::Status { status: 400, .. }))
+        ));
+        assert_eq!(
+            received.recv().unwrap().request_line,
+            "GET /2020/day/2/input HTTP/1.1"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn submit_to_stand_in_server() {
+        let (url, received) = stand_in::serve(vec![(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        )]);
+        let path = env::temp_dir().join(format!("aoc-cli-guesses-{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut submitter = Submitter::new(
+            UreqHttp::default(),
+            "secret",
+            GuessHistory::open(&path).unwrap(),
+        )
+        .with_base_url(&url);
+
+        let verdict = submitter.submit(2025, 3, Part::Two, &Answer::from(1234_u64));
+        assert_eq!(verdict.unwrap(), Verdict::TooLow);
+        let request = received.recv().unwrap();
+        assert_eq!(request.request_line, "POST /2025/day/3/answer HTTP/1.1");
+        assert_eq!(request.body, "level=2&answer=1234");
+        assert_eq!(submitter.history().guesses(2025, 3, Part::Two).count(), 1);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,98 @@
+use std::{path::PathBuf, str::FromStr};
+
+use aoc_common::{
+    download::{Downloader, session_token},
+    input::{InputError, InputResolver, Variant, read_file},
+};
+
+use crate::http::UreqHttp;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Variant(Variant),
+    Path(PathBuf),
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = PathBuf::from(s);
+        if s.contains(['/', '\\']) || path.is_file() {
+            return Ok(InputSource::Path(path));
+        }
+        s.parse().map(InputSource::Variant)
+    }
+}
+
+pub fn read_input(
+    resolver: &InputResolver,
+    year: u16,
+    day: u8,
+    source: &InputSource,
+) -> Result<String, InputError> {
+    match source {
+        InputSource::Path(path) => read_file(path),
+        InputSource::Variant(variant) => resolver.read(year, day, variant),
+    }
+}
+
+pub fn download_input(resolver: &InputResolver, year: u16, day: u8) -> Result<PathBuf, String> {
+    let session = session_token()
+        .ok_or("no session token, set AOC_SESSION or write it to ~/.config/aoc/session")?;
+    Downloader::new(UreqHttp::default(), resolver.clone(), session)
+        .fetch(year, day)
+        .map_err(|e| e.to_string())
+}
+
+/// a real input that is not found anywhere is downloaded when a session token is available
+pub fn read_or_download(
+    resolver: &InputResolver,
+    year: u16,
+    day: u8,
+    source: &InputSource,
+) -> Result<String, String> {
+    match read_input(resolver, year, day, source) {
+        Err(error @ InputError::NotFound { .. })
+            if *source == InputSource::Variant(Variant::Real) =>
+        {
+            if session_token().is_none() {
+                return Err(error.to_string());
+            }
+            let path = download_input(resolver, year, day)?;
+            read_file(&path).map_err(|e| e.to_string())
+        }
+        result => result.map_err(|e| e.to_string()),
+    }
+}
+
+#[test]
+fn parse_input_source() {
+    assert_eq!(
+        "example".parse(),
+        Ok(InputSource::Variant(Variant::Example))
+    );
+    assert_eq!("real".parse(), Ok(InputSource::Variant(Variant::Real)));
+    assert_eq!(
+        "example2".parse(),
+        Ok(InputSource::Variant(Variant::Extra("example2".to_string())))
+    );
+    assert_eq!(
+        "./custom.txt".parse(),
+        Ok(InputSource::Path(PathBuf::from("./custom.txt")))
+    );
+    assert!("".parse::<InputSource>().is_err());
+}
+
+#[test]
+fn read_known_layouts() {
+    let resolver = InputResolver::workspace();
+    let example = InputSource::Variant(Variant::Example);
+    assert!(read_input(&resolver, 2022, 15, &example).is_ok());
+    assert!(read_input(&resolver, 2023, 1, &example).is_ok());
+    assert!(read_input(&resolver, 2025, 7, &example).is_ok());
+    assert!(matches!(
+        read_input(&resolver, 2025, 30, &example),
+        Err(InputError::NotFound { .. })
+    ));
+}
@@ -0,0 +1,57 @@
+use std::{path::Path, process::ExitCode};
+
+use aoc_common::leaderboard::Leaderboard;
+
+/// h:mm:ss, days are counted in hours
+fn format(seconds: u64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Prints the standings and the part 2 deltas of a downloaded private leaderboard.
+pub fn leaderboard(path: &Path, day: Option<u8>, json: bool) -> Result<ExitCode, String> {
+    let leaderboard = Leaderboard::load(path).map_err(|e| e.to_string())?;
+    let summary = leaderboard.summary(day);
+    if json {
+        println!("{}", summary.to_json());
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    println!("rank  name                       stars  score  local  streak");
+    for (rank, standing) in summary.standings.iter().enumerate() {
+        println!(
+            "{:>4}  {:<25}  {:>5}  {:>5}  {:>5}  {:>6}",
+            rank + 1,
+            standing.name,
+            standing.stars,
+            standing.score,
+            standing.local_score,
+            standing.streak
+        );
+    }
+    for day in summary.days {
+        println!();
+        println!("day {:<21}      part 1      part 2       delta", day.day);
+        for result in day.results {
+            let optional = |seconds: Option<u64>| seconds.map(format).unwrap_or_default();
+            println!(
+                "{:<25}  {:>10}  {:>10}  {:>10}",
+                result.name,
+                format(result.part_1),
+                optional(result.part_2),
+                optional(result.delta)
+            );
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+#[test]
+fn format_duration() {
+    assert_eq!(format(59), "0:00:59");
+    assert_eq!(format(27 * 3600 + 61), "27:01:01");
+}
@@ -0,0 +1,186 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_common::{
+    answers::Answers,
+    input::{InputResolver, Variant},
+    registry::Registry,
+};
+use clap::{Parser, Subcommand};
+
+use crate::inputs::InputSource;
+
+mod bench;
+mod http;
+mod inputs;
+mod leaderboard;
+mod run;
+mod scaffold;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the advent of code solutions of every year")]
+struct Cli {
+    /// searched first for inputs, defaults to $AOC_INPUT_DIR or inputs/ in the repository root,
+    /// the closest directory up from here with an answers.toml
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day
+    Run {
+        year: u16,
+        day: u8,
+        /// only run this part, both parts are run by default
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// example, real, another variant like example2 or a path to an input file
+        #[arg(short, long, default_value = "real")]
+        input: InputSource,
+        /// fail when the answer differs from this value
+        #[arg(short, long, requires = "part")]
+        expect: Option<String>,
+        /// submit the answer, guesses that are known to be wrong are refused
+        #[arg(long, requires = "part")]
+        submit: bool,
+    },
+    /// Check the registered days against the answers file
+    Verify {
+        #[arg(short, long)]
+        year: Option<u16>,
+        #[arg(short, long, requires = "year")]
+        day: Option<u8>,
+        /// only verify this input variant
+        #[arg(short, long)]
+        variant: Option<Variant>,
+    },
+    /// Time the days of a year over repeated runs
+    Bench {
+        year: u16,
+        day: Option<u8>,
+        #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        #[arg(short, long, default_value = "real")]
+        input: Variant,
+        /// write the results as a json report
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// an earlier report to compare the medians with
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+        /// slowdown in percent that counts as a regression
+        #[arg(short, long, default_value_t = 10.0, requires = "baseline")]
+        threshold: f64,
+    },
+    /// Download the real input of a day into the input directory
+    Download { year: u16, day: u8 },
+    /// Analyse a private leaderboard json file
+    Leaderboard {
+        file: PathBuf,
+        /// only show the results of this day
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// print the summary as json
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create the files of a new day and register it
+    New { year: u16, day: u8 },
+    /// List every registered day
+    List,
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    aoc_lib::register(&mut registry);
+    aoc_2023_rust::register(&mut registry);
+    aoc_2025_rust::register(&mut registry);
+    registry
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let registry = registry();
+    let resolver = match cli.input_dir {
+        Some(dir) => InputResolver::workspace().with_input_dir(dir),
+        None => InputResolver::workspace(),
+    };
+    let result = match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            expect,
+            submit,
+        } => {
+            let options = run::RunOptions {
+                part,
+                input,
+                expect,
+                submit,
+            };
+            run::run(&registry, &resolver, year, day, &options)
+        }
+        Command::Verify { year, day, variant } => {
+            let options = verify::VerifyOptions { year, day, variant };
+            Answers::workspace()
+                .map_err(|e| e.to_string())
+                .and_then(|answers| verify::verify(&registry, &resolver, &answers, &options))
+        }
+        Command::Bench {
+            year,
+            day,
+            runs,
+            input,
+            output,
+            baseline,
+            threshold,
+        } => {
+            let options = bench::BenchOptions {
+                day,
+                runs: runs as usize,
+                variant: input,
+                output,
+                baseline,
+                threshold,
+            };
+            bench::bench(&registry, &resolver, year, &options)
+        }
+        Command::Download { year, day } => {
+            inputs::download_input(&resolver, year, day).map(|path| {
+                println!("{}", path.display());
+                ExitCode::SUCCESS
+            })
+        }
+        Command::Leaderboard { file, day, json } => leaderboard::leaderboard(&file, day, json),
+        Command::New { year, day } => {
+            scaffold::new_day(resolver.root(), year, day).map(|created| {
+                for path in created {
+                    println!("{}", path.display());
+                }
+                ExitCode::SUCCESS
+            })
+        }
+        Command::List => {
+            for year in registry.years() {
+                let days: Vec<String> = registry
+                    .iter_year(year)
+                    .map(|puzzle| puzzle.day().to_string())
+                    .collect();
+                println!("{}: {}", year, days.join(", "));
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+    };
+    match result {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use std::process::ExitCode;
+
+use aoc_common::{
+    download::session_token,
+    input::{InputResolver, Variant},
+    registry::Registry,
+    solution::Part,
+    submit::{GuessHistory, Submitter, Verdict},
+};
+
+use crate::{
+    http::UreqHttp,
+    inputs::{InputSource, read_or_download},
+};
+
+pub struct RunOptions {
+    pub part: Option<u8>,
+    pub input: InputSource,
+    pub expect: Option<String>,
+    /// submit the answer of the part, only for the real input
+    pub submit: bool,
+}
+
+pub fn run(
+    registry: &Registry,
+    resolver: &InputResolver,
+    year: u16,
+    day: u8,
+    options: &RunOptions,
+) -> Result<ExitCode, String> {
+    let puzzle = registry
+        .get(year, day)
+        .ok_or_else(|| format!("{} day {} is not registered", year, day))?;
+    let parts: Vec<Part> = match options.part {
+        Some(part) => vec![Part::new(part).ok_or_else(|| format!("invalid part {}", part))?],
+        None => Part::ALL.to_vec(),
+    };
+    if options.submit && options.input != InputSource::Variant(Variant::Real) {
+        return Err("only answers for the real input can be submitted".to_string());
+    }
+    let input = read_or_download(resolver, year, day, &options.input)?;
+    // an input file of our own is taken as a real puzzle input
+    let variant = match &options.input {
+        InputSource::Variant(variant) => variant.clone(),
+        InputSource::Path(_) => Variant::Real,
+    };
+
+    println!("{} day {}", year, day);
+    let run = puzzle.run(&input, &variant, &parts);
+    println!("parse    {:?}", run.parse_duration);
+
+    let mut code = ExitCode::SUCCESS;
+    for part_run in run.parts.iter() {
+        println!(
+            "part {}   {} ({:?})",
+            part_run.part, part_run.answer, part_run.duration
+        );
+        if let Some(expected) = &options.expect
+            && part_run.answer.to_string() != *expected
+        {
+            eprintln!("part {} expected {}", part_run.part, expected);
+            code = ExitCode::FAILURE;
+        }
+    }
+
+    if options.submit {
+        let session = session_token().ok_or("no session token to submit with")?;
+        let history = GuessHistory::open(resolver.input_dir().join("guesses.tsv"))
+            .map_err(|e| format!("guess history: {}", e))?;
+        let mut submitter = Submitter::new(UreqHttp::default(), session, history);
+        for part_run in run.parts.iter() {
+            let verdict = submitter
+                .submit(year, day, part_run.part, &part_run.answer)
+                .map_err(|e| e.to_string())?;
+            println!("part {}   {}", part_run.part, verdict);
+            if verdict != Verdict::Correct {
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    Ok(code)
+}
@@ -0,0 +1,442 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The way a year crate lays out its days.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Layout {
+    /// `src/days/day_N/main_N.rs` with the inputs next to it, tests in the day module
+    DayModules,
+    /// `src/days/day_NN.rs`, a bin that checks the answers in `main` and the inputs in `src/<year>/data`
+    SharedData,
+    /// `src/days/day_NN.rs` and a bin with the inputs and the tests
+    BinTests,
+}
+
+impl Layout {
+    fn of(year: u16) -> Self {
+        match year {
+            ..=2022 => Layout::DayModules,
+            2023 => Layout::SharedData,
+            _ => Layout::BinTests,
+        }
+    }
+}
+
+const DAY_MODULE: &str = r#"use aoc_common::solution::Solution;
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
+
+pub fn part_1(_input: &str) -> usize {
+    0
+}
+
+pub fn part_2(_input: &str) -> usize {
+    0
+}
+
+pub struct Day{day};
+impl Solution for Day{day} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
+
+#[test]
+fn day_{day}_part_1_example() {
+    assert_answer(&Day{day}, &Variant::Example, Part::One, include_str!("input.example"));
+}
+#[test]
+fn day_{day}_part_1() {
+    assert_answer(&Day{day}, &Variant::Real, Part::One, include_str!("input"));
+}
+#[test]
+fn day_{day}_part_2_example() {
+    assert_answer(&Day{day}, &Variant::Example, Part::Two, include_str!("input.example"));
+}
+#[test]
+fn day_{day}_part_2() {
+    assert_answer(&Day{day}, &Variant::Real, Part::Two, include_str!("input"));
+}
+"#;
+
+const SHARED_DATA_DAY: &str = r#"use aoc_common::solution::Solution;
+
+pub fn part_1(_input: &str) -> usize {
+    0
+}
+
+pub fn part_2(_input: &str) -> usize {
+    0
+}
+
+pub struct Day{dd};
+impl Solution for Day{dd} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+}
+"#;
+
+const SHARED_DATA_BIN: &str = r#"use aoc_{year}_rust::days::day_{dd}::Day{dd};
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
+
+fn main() {
+    let example_input = include_str!("../../../../src/{year}/data/y{year}_day{dd}.example");
+    let input = include_str!("../../../../src/{year}/data/y{year}_day{dd}.input");
+    assert_answer(&Day{dd}, &Variant::Example, Part::One, example_input);
+    let part_1_result = assert_answer(&Day{dd}, &Variant::Real, Part::One, input);
+    println!("part 1 - {}", part_1_result);
+
+    assert_answer(&Day{dd}, &Variant::Example, Part::Two, example_input);
+    let part_2_result = assert_answer(&Day{dd}, &Variant::Real, Part::Two, input);
+    println!("part 2 - {}", part_2_result);
+}
+"#;
+
+const BIN_TESTS_DAY: &str = r#"use aoc_common::solution::Solution;
+
+pub fn execute_part1(_input: &str) -> usize {
+    0
+}
+
+pub fn execute_part2(_input: &str) -> usize {
+    0
+}
+
+pub struct Day{dd};
+impl Solution for Day{dd} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+        input
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        execute_part1(input)
+    }
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        execute_part2(input)
+    }
+}
+"#;
+
+const BIN_TESTS_BIN: &str = r#"use aoc_{year}_rust::days::day_{dd} as part1;
+#[cfg(test)]
+use aoc_{year}_rust::days::day_{dd}::Day{dd};
+#[cfg(test)]
+use aoc_common::{answers::assert_answer, input::Variant, solution::Part};
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = include_str!("./y{year}_day{dd}.example");
+const INPUT: &str = include_str!("./y{year}_day{dd}.input");
+
+#[test]
+fn part1_example() {
+    assert_answer(&Day{dd}, &Variant::Example, Part::One, EXAMPLE_INPUT);
+}
+
+#[test]
+fn part1_input() {
+    assert_answer(&Day{dd}, &Variant::Real, Part::One, INPUT);
+}
+
+#[test]
+fn part2_example() {
+    assert_answer(&Day{dd}, &Variant::Example, Part::Two, EXAMPLE_INPUT);
+}
+
+#[test]
+fn part2_input() {
+    assert_answer(&Day{dd}, &Variant::Real, Part::Two, INPUT);
+}
+
+fn main() {
+    println!("part 1 - {}", part1::execute_part1(INPUT));
+    println!("part 2 - {}", part1::execute_part2(INPUT));
+}
+"#;
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{dd}", &format!("{:02}", day))
+        .replace("{day}", &day.to_string())
+}
+
+/// Adds the module, the registration and bumps the day count of the `register_days` test.
+fn register(mod_file: &str, year: u16, module: &str, solution: &str) -> Result<String, String> {
+    let mut lines: Vec<String> = mod_file.lines().map(|line| line.to_string()).collect();
+    let is_module = |line: &str| line.trim_start_matches("pub ").starts_with("mod day_");
+    // follow the visibility of the first day module
+    let module_line = match lines.iter().find(|line| is_module(line)) {
+        Some(line) if line.starts_with("pub ") => format!("pub mod {};", module),
+        _ => format!("mod {};", module),
+    };
+    // keep the modules sorted by day, day_9 before day_10
+    let day_number = |line: &str| -> Option<u32> {
+        let name = line.trim_start_matches("pub ").strip_prefix("mod day_")?;
+        name.split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok()
+    };
+    let new_day = day_number(&module_line).unwrap();
+    let modules: Vec<usize> = (0..lines.len()).filter(|i| is_module(&lines[*i])).collect();
+    let position = modules
+        .iter()
+        .find(|i| day_number(&lines[**i]).is_some_and(|day| day > new_day))
+        .copied()
+        .or_else(|| modules.last().map(|i| i + 1))
+        .ok_or("no day modules found")?;
+    lines.insert(position, module_line);
+
+    let register_end = lines
+        .iter()
+        .position(|line| line.starts_with("pub fn register("))
+        .and_then(|start| (start..lines.len()).find(|i| lines[*i] == "}"))
+        .ok_or("no register function found")?;
+    lines.insert(
+        register_end,
+        format!("    registry.register({});", solution),
+    );
+
+    let count_prefix = format!("    assert_eq!(registry.iter_year({}).count(), ", year);
+    for line in lines.iter_mut() {
+        if let Some(count) = line
+            .strip_prefix(&count_prefix)
+            .and_then(|rest| rest.strip_suffix(");"))
+            .and_then(|count| count.parse::<usize>().ok())
+        {
+            *line = format!("{}{});", count_prefix, count + 1);
+        }
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn create(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("{}: {}", parent.display(), e))?;
+    }
+    fs::write(path, content).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Creates the files of a new day in the crate of the year, returns the created files.
+/// Inputs that already exist, like the data of the typescript solutions, are kept.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {} is not an advent of code day", day));
+    }
+    let crate_dir = root.join(format!("aoc-{}-rust", year));
+    if !crate_dir.is_dir() {
+        return Err(format!(
+            "there is no crate for {}, {} is missing",
+            year,
+            crate_dir.display()
+        ));
+    }
+    let src = crate_dir.join("src");
+    let dd = format!("{:02}", day);
+
+    let (module, solution, mut files, inputs) = match Layout::of(year) {
+        Layout::DayModules => {
+            let dir = src.join(format!("days/day_{}", day));
+            let files = vec![
+                (dir.join("mod.rs"), format!("pub mod main_{};\n", day)),
+                (
+                    dir.join(format!("main_{}.rs", day)),
+                    fill(DAY_MODULE, year, day),
+                ),
+            ];
+            let inputs = vec![dir.join("input"), dir.join("input.example")];
+            let solution = format!("day_{}::main_{}::Day{}", day, day, day);
+            (format!("day_{}", day), solution, files, inputs)
+        }
+        Layout::SharedData => {
+            let files = vec![
+                (
+                    src.join(format!("days/day_{}.rs", dd)),
+                    fill(SHARED_DATA_DAY, year, day),
+                ),
+                (
+                    src.join(format!("bin/day_{}/main.rs", dd)),
+                    fill(SHARED_DATA_BIN, year, day),
+                ),
+            ];
+            let data = root.join(format!("src/{}/data", year));
+            let inputs = vec![
+                data.join(format!("y{}_day{}.example", year, dd)),
+                data.join(format!("y{}_day{}.input", year, dd)),
+            ];
+            (
+                format!("day_{}", dd),
+                format!("day_{}::Day{}", dd, dd),
+                files,
+                inputs,
+            )
+        }
+        Layout::BinTests => {
+            let bin = src.join(format!("bin/day_{}", dd));
+            let files = vec![
+                (
+                    src.join(format!("days/day_{}.rs", dd)),
+                    fill(BIN_TESTS_DAY, year, day),
+                ),
+                (bin.join("main.rs"), fill(BIN_TESTS_BIN, year, day)),
+            ];
+            let inputs = vec![
+                bin.join(format!("y{}_day{}.example", year, dd)),
+                bin.join(format!("y{}_day{}.input", year, dd)),
+            ];
+            (
+                format!("day_{}", dd),
+                format!("day_{}::Day{}", dd, dd),
+                files,
+                inputs,
+            )
+        }
+    };
+    if let Some((existing, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(format!("{} already exists", existing.display()));
+    }
+
+    let mod_path = src.join("days/mod.rs");
+    let mod_file =
+        fs::read_to_string(&mod_path).map_err(|e| format!("{}: {}", mod_path.display(), e))?;
+    files.push((mod_path, register(&mod_file, year, &module, &solution)?));
+    files.extend(
+        inputs
+            .into_iter()
+            .filter(|path| !path.exists())
+            .map(|path| (path, String::new())),
+    );
+
+    for (path, content) in files.iter() {
+        create(path, content)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const MOD_FILE: &str = "use aoc_common::registry::Registry;
+
+pub mod day_01;
+pub mod day_10;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(day_01::Day01);
+    registry.register(day_10::Day10);
+}
+
+#[test]
+fn register_days() {
+    let mut registry = Registry::new();
+    register(&mut registry);
+    assert_eq!(registry.iter_year(2025).count(), 2);
+}
+";
+
+    #[test]
+    fn register_in_mod_file() {
+        let registered = register(MOD_FILE, 2025, "day_02", "day_02::Day02").unwrap();
+        assert!(registered.contains("pub mod day_01;\npub mod day_02;\npub mod day_10;"));
+        assert!(registered.contains(
+            "    registry.register(day_10::Day10);\n    registry.register(day_02::Day02);\n}"
+        ));
+        assert!(registered.contains("count(), 3);"));
+
+        let private =
+            "mod day_1;\nmod day_16_1;\n\npub fn register(registry: &mut Registry) {\n}\n";
+        let registered = register(private, 2022, "day_17", "day_17::main_17::Day17").unwrap();
+        assert!(registered.starts_with("mod day_1;\nmod day_16_1;\nmod day_17;\n"));
+    }
+
+    #[test]
+    fn scaffold_every_layout() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for year in [2022, 2023, 2025] {
+            let days = root.join(format!("aoc-{}-rust/src/days", year));
+            fs::create_dir_all(&days).unwrap();
+            fs::write(
+                days.join("mod.rs"),
+                MOD_FILE.replace("2025", &year.to_string()),
+            )
+            .unwrap();
+        }
+
+        let created = new_day(&root, 2022, 5).unwrap();
+        assert!(created.contains(&root.join("aoc-2022-rust/src/days/day_5/main_5.rs")));
+        assert!(
+            root.join("aoc-2022-rust/src/days/day_5/input.example")
+                .is_file()
+        );
+
+        fs::create_dir_all(root.join("src/2023/data")).unwrap();
+        fs::write(root.join("src/2023/data/y2023_day04.input"), "kept").unwrap();
+        new_day(&root, 2023, 4).unwrap();
+        let bin = fs::read_to_string(root.join("aoc-2023-rust/src/bin/day_04/main.rs")).unwrap();
+        assert!(bin.contains("y2023_day04.example\");"));
+        assert!(bin.contains("y2023_day04.input\");"));
+        assert_eq!(
+            fs::read_to_string(root.join("src/2023/data/y2023_day04.input")).unwrap(),
+            "kept"
+        );
+
+        new_day(&root, 2025, 13).unwrap();
+        let day = fs::read_to_string(root.join("aoc-2025-rust/src/days/day_13.rs")).unwrap();
+        assert!(day.contains("pub struct Day13;"));
+        assert!(day.contains("const DAY: u8 = 13;"));
+        let mod_file = fs::read_to_string(root.join("aoc-2025-rust/src/days/mod.rs")).unwrap();
+        assert!(mod_file.contains("registry.register(day_13::Day13);"));
+        assert!(
+            root.join("aoc-2025-rust/src/bin/day_13/y2025_day13.input")
+                .is_file()
+        );
+
+        assert!(
+            new_day(&root, 2025, 13)
+                .unwrap_err()
+                .ends_with("already exists")
+        );
+        assert!(new_day(&root, 2024, 1).is_err());
+        assert!(new_day(&root, 2025, 26).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,262 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc_common::{
+    answers::{Answers, Check},
+    input::{InputError, InputResolver, Variant},
+    registry::Registry,
+    solution::{Answer, Part, Puzzle},
+};
+
+pub struct VerifyOptions {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub variant: Option<Variant>,
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    variant: Variant,
+    part: Part,
+    answer: String,
+    expected: String,
+    duration: Option<Duration>,
+    check: Check,
+}
+
+/// example and real are always verified, other variants only for the parts with an answer
+fn variants(answers: &Answers, year: u16, day: u8, only: Option<&Variant>) -> Vec<Variant> {
+    let mut variants = vec![Variant::Example, Variant::Real];
+    for variant in answers.variants(year, day) {
+        if !variants.contains(&variant) {
+            variants.push(variant);
+        }
+    }
+    variants.retain(|variant| only.is_none_or(|only| only == variant));
+    variants
+}
+
+/// None when the part panicked
+fn run_part(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    variant: &Variant,
+    part: Part,
+) -> Option<(Answer, Duration)> {
+    let mut run =
+        panic::catch_unwind(AssertUnwindSafe(|| puzzle.run(input, variant, &[part]))).ok()?;
+    let part_run = run.parts.remove(0);
+    Some((part_run.answer, part_run.duration))
+}
+
+fn verify_variant(
+    puzzle: &dyn Puzzle,
+    resolver: &InputResolver,
+    answers: &Answers,
+    variant: &Variant,
+) -> Result<Vec<Row>, InputError> {
+    let (year, day) = (puzzle.year(), puzzle.day());
+    let parts: Vec<Part> = match variant {
+        Variant::Example | Variant::Real => Part::ALL.to_vec(),
+        Variant::Extra(_) => Part::ALL
+            .into_iter()
+            .filter(|part| answers.get(year, day, *part, variant).is_some())
+            .collect(),
+    };
+    let input = match resolver.read(year, day, variant) {
+        Ok(input) => input,
+        Err(InputError::NotFound { .. }) => {
+            // without input only the expected answers are worth a row
+            let rows = parts
+                .into_iter()
+                .filter_map(|part| {
+                    let expected = answers.get(year, day, part, variant)?;
+                    Some(Row {
+                        year,
+                        day,
+                        variant: variant.clone(),
+                        part,
+                        answer: "no input".to_string(),
+                        expected: expected.to_string(),
+                        duration: None,
+                        check: Check::Missing,
+                    })
+                })
+                .collect();
+            return Ok(rows);
+        }
+        Err(error) => return Err(error),
+    };
+
+    let rows = parts
+        .into_iter()
+        .map(|part| {
+            let expected = answers.get(year, day, part, variant);
+            let (answer, duration, check) = match run_part(puzzle, &input, variant, part) {
+                Some((answer, duration)) => {
+                    let check = answers.check(year, day, part, variant, &answer);
+                    (answer.to_string(), Some(duration), check)
+                }
+                None => {
+                    let check = match expected {
+                        Some(expected) => Check::Fail {
+                            expected: expected.to_string(),
+                        },
+                        None => Check::Missing,
+                    };
+                    ("panicked".to_string(), None, check)
+                }
+            };
+            Row {
+                year,
+                day,
+                variant: variant.clone(),
+                part,
+                answer,
+                expected: expected.unwrap_or("-").to_string(),
+                duration,
+                check,
+            }
+        })
+        .collect();
+    Ok(rows)
+}
+
+/// Runs the registered days against the answers file and prints a table of the results.
+pub fn verify(
+    registry: &Registry,
+    resolver: &InputResolver,
+    answers: &Answers,
+    options: &VerifyOptions,
+) -> Result<ExitCode, String> {
+    let puzzles: Vec<&dyn Puzzle> = registry
+        .iter()
+        .filter(|puzzle| options.year.is_none_or(|year| year == puzzle.year()))
+        .filter(|puzzle| options.day.is_none_or(|day| day == puzzle.day()))
+        .collect();
+    if puzzles.is_empty() {
+        return Err("no registered days to verify".to_string());
+    }
+
+    println!("year  day  variant   part            answer          expected          time  status");
+    // the answer column already says which parts panicked
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for puzzle in puzzles {
+        for variant in variants(
+            answers,
+            puzzle.year(),
+            puzzle.day(),
+            options.variant.as_ref(),
+        ) {
+            let rows = match verify_variant(puzzle, resolver, answers, &variant) {
+                Ok(rows) => rows,
+                Err(error) => {
+                    panic::set_hook(hook);
+                    return Err(error.to_string());
+                }
+            };
+            for row in rows {
+                match row.check {
+                    Check::Pass => passed += 1,
+                    Check::Fail { .. } => failed += 1,
+                    Check::Missing => missing += 1,
+                }
+                let duration = row
+                    .duration
+                    .map(|duration| format!("{:.2?}", duration))
+                    .unwrap_or_default();
+                println!(
+                    "{:<4}  {:>3}  {:<8}  {:>4}  {:>16}  {:>16}  {:>12}  {}",
+                    row.year,
+                    row.day,
+                    row.variant.to_string(),
+                    row.part.to_string(),
+                    row.answer,
+                    row.expected,
+                    duration,
+                    row.check
+                );
+            }
+        }
+    }
+    panic::set_hook(hook);
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    match failed {
+        0 => Ok(ExitCode::SUCCESS),
+        _ => Ok(ExitCode::FAILURE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use aoc_common::solution::Solution;
+
+    use super::*;
+
+    struct Double;
+    impl Solution for Double {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Input<'a> = i64;
+        type Part1 = i64;
+        type Part2 = i64;
+
+        fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+            input.trim().parse().unwrap()
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+            input * 2
+        }
+
+        fn part_2(&self, _input: &Self::Input<'_>) -> Self::Part2 {
+            panic!("not solved")
+        }
+    }
+
+    const ANSWERS: &str = "[2000.1.example]\npart1 = 4\npart2 = 1\n[2000.1.real]\npart1 = 1\n[2000.1.example2]\npart2 = 3";
+
+    #[test]
+    fn verified_variants() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let extra = Variant::Extra("example2".to_string());
+        assert_eq!(
+            variants(&answers, 2000, 1, None),
+            vec![Variant::Example, Variant::Real, extra.clone()]
+        );
+        assert_eq!(variants(&answers, 2000, 1, Some(&extra)), vec![extra]);
+    }
+
+    #[test]
+    fn verify_rows() {
+        let root = env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        let data = root.join("src/2000/data");
+        fs::create_dir_all(&data).unwrap();
+        fs::write(data.join("y2000_day01.example"), "2").unwrap();
+        let resolver = InputResolver::new(&root);
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        let rows = verify_variant(&Double, &resolver, &answers, &Variant::Example).unwrap();
+        assert_eq!(rows[0].answer, "4");
+        assert_eq!(rows[0].check, Check::Pass);
+        assert_eq!(rows[1].answer, "panicked");
+        assert!(matches!(rows[1].check, Check::Fail { .. }));
+
+        // no input, only the parts with an answer are listed
+        let rows = verify_variant(&Double, &resolver, &answers, &Variant::Real).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].check, Check::Missing);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,311 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use toml::{Table, Value};
+
+use crate::{
+    input::{Variant, workspace_root},
+    solution::{Answer, Part, Puzzle},
+};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Read { path: PathBuf, source: io::Error },
+    Parse(String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Read { path, source } => write!(f, "{}: {}", path.display(), source),
+            AnswersError::Parse(message) => write!(f, "invalid answers, {}", message),
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// there is no expected answer
+    Missing,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Pass => write!(f, "pass"),
+            Check::Fail { .. } => write!(f, "fail"),
+            Check::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+/// The expected answers, read from a toml file with a table per year, day and variant:
+///
+/// ```toml
+/// [2022.15.example]
+/// part1 = 26
+/// part2 = 56000011
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: BTreeMap<(u16, u8, Variant, Part), String>,
+}
+
+fn table<'a>(value: &'a Value, key: &str) -> Result<&'a Table, AnswersError> {
+    value
+        .as_table()
+        .ok_or_else(|| AnswersError::Parse(format!("{} should be a table", key)))
+}
+
+fn number<T: std::str::FromStr>(key: &str) -> Result<T, AnswersError> {
+    key.parse()
+        .map_err(|_| AnswersError::Parse(format!("{} is not a number", key)))
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let root: Table = text
+            .parse()
+            .map_err(|e: toml::de::Error| AnswersError::Parse(e.message().to_string()))?;
+        let mut expected = BTreeMap::new();
+        for (year_key, days) in root.iter() {
+            let year = number(year_key)?;
+            for (day_key, variants) in table(days, year_key)? {
+                let day = number(day_key)?;
+                for (variant_key, parts) in table(variants, day_key)? {
+                    let variant: Variant = variant_key.parse().map_err(AnswersError::Parse)?;
+                    for (part_key, answer) in table(parts, variant_key)? {
+                        let part = part_key
+                            .strip_prefix("part")
+                            .and_then(|part| Part::new(part.parse().ok()?))
+                            .ok_or_else(|| {
+                                AnswersError::Parse(format!("unknown part {}", part_key))
+                            })?;
+                        let answer = match answer {
+                            Value::Integer(value) => value.to_string(),
+                            Value::String(value) => value.clone(),
+                            _ => {
+                                return Err(AnswersError::Parse(format!(
+                                    "{}.{}.{}.{} should be a number or a string",
+                                    year_key, day_key, variant_key, part_key
+                                )));
+                            }
+                        };
+                        expected.insert((year, day, variant.clone(), part), answer);
+                    }
+                }
+            }
+        }
+        Ok(Answers { expected })
+    }
+
+    /// a missing file has no answers
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(source) => Err(AnswersError::Read {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    /// the answers file in the root of the repository the current directory is in
+    pub fn workspace() -> Result<Self, AnswersError> {
+        Self::load(&workspace_root().join(ANSWERS_FILE))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part, variant: &Variant) -> Option<&str> {
+        self.expected
+            .get(&(year, day, variant.clone(), part))
+            .map(|answer| answer.as_str())
+    }
+
+    /// every variant of the day with at least one expected answer
+    pub fn variants(&self, year: u16, day: u8) -> Vec<Variant> {
+        let mut variants: Vec<Variant> = self
+            .expected
+            .keys()
+            .filter(|(y, d, _, _)| *y == year && *d == day)
+            .map(|(_, _, variant, _)| variant.clone())
+            .collect();
+        variants.dedup();
+        variants
+    }
+
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        variant: &Variant,
+        answer: &Answer,
+    ) -> Check {
+        match self.get(year, day, part, variant) {
+            None => Check::Missing,
+            Some(expected) if *answer != Answer::Unsolved && answer.to_string() == expected => {
+                Check::Pass
+            }
+            Some(expected) => Check::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.expected.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.expected.is_empty()
+    }
+}
+
+/// Runs one part and checks it against the answers file, this is what the day tests use.
+/// Without an expected answer the result is only printed.
+pub fn assert_answer<P: Puzzle + ?Sized>(
+    puzzle: &P,
+    variant: &Variant,
+    part: Part,
+    input: &str,
+) -> Answer {
+    let answers = Answers::workspace().unwrap_or_else(|e| panic!("{}", e));
+    let answer = puzzle.run(input, variant, &[part]).parts.remove(0).answer;
+    let (year, day) = (puzzle.year(), puzzle.day());
+    match answers.check(year, day, part, variant, &answer) {
+        Check::Pass => {}
+        Check::Fail { expected } => panic!(
+            "{} day {} part {} {}: expected {}, got {}",
+            year, day, part, variant, expected, answer
+        ),
+        Check::Missing => println!(
+            "{} day {} part {} {}: no expected answer for {}",
+            year, day, part, variant, answer
+        ),
+    }
+    answer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[2022.15.example]
+part1 = 26
+part2 = 56000011
+
+[2022.15.real]
+part1 = 4883971
+
+[2023.1.example2]
+part2 = "281"
+"#;
+
+    #[test]
+    fn parse_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.len(), 4);
+        assert_eq!(
+            answers.get(2022, 15, Part::Two, &Variant::Example),
+            Some("56000011")
+        );
+        assert_eq!(answers.get(2022, 15, Part::Two, &Variant::Real), None);
+        assert_eq!(
+            answers.get(2023, 1, Part::Two, &Variant::Extra("example2".to_string())),
+            Some("281")
+        );
+        assert_eq!(
+            answers.variants(2022, 15),
+            vec![Variant::Real, Variant::Example]
+        );
+    }
+
+    #[test]
+    fn check_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let check =
+            |part, variant, answer: Answer| answers.check(2022, 15, part, &variant, &answer);
+        assert_eq!(
+            check(Part::One, Variant::Example, Answer::from(26)),
+            Check::Pass
+        );
+        assert_eq!(
+            check(Part::One, Variant::Real, Answer::from(1)),
+            Check::Fail {
+                expected: "4883971".to_string()
+            }
+        );
+        assert_eq!(
+            check(Part::Two, Variant::Real, Answer::from(1)),
+            Check::Missing
+        );
+    }
+
+    #[test]
+    fn invalid_answers() {
+        assert!(Answers::parse("[2022.15.example]\npart3 = 1").is_err());
+        assert!(Answers::parse("[2022.day.example]\npart1 = 1").is_err());
+        assert!(Answers::parse("[2022.15.example]\npart1 = 1.5").is_err());
+    }
+
+    struct Constant(i64);
+    impl crate::solution::Solution for Constant {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 15;
+
+        type Input<'a> = ();
+        type Part1 = i64;
+        type Part2 = Answer;
+
+        fn parse<'a>(&self, _input: &'a str) -> Self::Input<'a> {}
+
+        fn part_1(&self, _input: &Self::Input<'_>) -> Self::Part1 {
+            self.0
+        }
+
+        fn part_2(&self, _input: &Self::Input<'_>) -> Self::Part2 {
+            Answer::Unsolved
+        }
+    }
+
+    #[test]
+    fn assert_expected_answer() {
+        assert_eq!(
+            assert_answer(&Constant(26), &Variant::Example, Part::One, ""),
+            Answer::from(26)
+        );
+        assert_answer(
+            &Constant(26),
+            &Variant::Extra("none".to_string()),
+            Part::One,
+            "",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 26, got 25")]
+    fn assert_wrong_answer() {
+        assert_answer(&Constant(25), &Variant::Example, Part::One, "");
+    }
+
+    #[test]
+    fn workspace_answers() {
+        let answers = Answers::workspace().unwrap();
+        assert!(!answers.is_empty());
+    }
+}
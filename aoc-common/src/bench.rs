@@ -0,0 +1,336 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input::Variant,
+    solution::{Part, Puzzle},
+};
+
+/// Summary of repeated timings, stored in nanoseconds so reports stay readable as json.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+    pub stddev: u64,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "stats need at least one sample");
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+        let middle = nanos.len() / 2;
+        let median = match nanos.len() % 2 {
+            0 => (nanos[middle - 1] + nanos[middle]) / 2,
+            _ => nanos[middle],
+        };
+        let mean = nanos.iter().map(|n| *n as f64).sum::<f64>() / nanos.len() as f64;
+        let variance = nanos
+            .iter()
+            .map(|n| (*n as f64 - mean).powi(2))
+            .sum::<f64>()
+            / nanos.len() as f64;
+        Stats {
+            min: nanos[0],
+            median,
+            mean: mean.round() as u64,
+            stddev: variance.sqrt().round() as u64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = |nanos| format!("{:.2?}", Duration::from_nanos(nanos));
+        write!(
+            f,
+            "min {} median {} mean {} stddev {}",
+            format(self.min),
+            format(self.median),
+            format(self.mean),
+            format(self.stddev)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartBench {
+    pub part: u8,
+    pub answer: String,
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub variant: String,
+    pub parse: Stats,
+    pub parts: Vec<PartBench>,
+}
+
+impl DayBench {
+    /// parse and every part are timed `runs` times
+    pub fn measure(
+        puzzle: &dyn Puzzle,
+        input: &str,
+        variant: &Variant,
+        parts: &[Part],
+        runs: usize,
+    ) -> Self {
+        assert!(runs > 0, "benchmarks need at least one run");
+        let mut parse = Vec::with_capacity(runs);
+        let mut durations = vec![Vec::with_capacity(runs); parts.len()];
+        let mut answers = vec![String::new(); parts.len()];
+        for _ in 0..runs {
+            let run = puzzle.run(input, variant, parts);
+            parse.push(run.parse_duration);
+            for (index, part_run) in run.parts.into_iter().enumerate() {
+                durations[index].push(part_run.duration);
+                answers[index] = part_run.answer.to_string();
+            }
+        }
+        DayBench {
+            year: puzzle.year(),
+            day: puzzle.day(),
+            variant: variant.to_string(),
+            parse: Stats::new(&parse),
+            parts: parts
+                .iter()
+                .zip(answers)
+                .zip(durations)
+                .map(|((part, answer), durations)| PartBench {
+                    part: part.number(),
+                    answer,
+                    stats: Stats::new(&durations),
+                })
+                .collect(),
+        }
+    }
+
+    /// (name, stats) of parse and each part
+    pub fn timings(&self) -> Vec<(String, Stats)> {
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| (format!("part {}", part.part), part.stats));
+        std::iter::once(("parse".to_string(), self.parse))
+            .chain(parts)
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub enum ReportError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+impl Display for ReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            ReportError::Json { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for ReportError {}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub runs: usize,
+    pub days: Vec<DayBench>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, ReportError> {
+        let text = fs::read_to_string(path).map_err(|source| ReportError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        serde_json::from_str(&text).map_err(|source| ReportError::Json {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ReportError> {
+        let json = serde_json::to_string_pretty(self).map_err(|source| ReportError::Json {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        fs::write(path, json + "\n").map_err(|source| ReportError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    fn find(&self, year: u16, day: u8, variant: &str) -> Option<&DayBench> {
+        self.days
+            .iter()
+            .find(|bench| bench.year == year && bench.day == day && bench.variant == variant)
+    }
+
+    /// Compares the medians with the baseline, anything slower than
+    /// `threshold` (0.1 is 10%) counts as a regression.
+    pub fn compare(&self, baseline: &Report, threshold: f64) -> Vec<Comparison> {
+        let mut comparisons = vec![];
+        for bench in self.days.iter() {
+            let Some(base) = baseline.find(bench.year, bench.day, &bench.variant) else {
+                continue;
+            };
+            let base_timings = base.timings();
+            for (name, stats) in bench.timings() {
+                let Some((_, base_stats)) = base_timings.iter().find(|(n, _)| *n == name) else {
+                    continue;
+                };
+                let change = match base_stats.median {
+                    0 => 0.0,
+                    median => stats.median as f64 / median as f64 - 1.0,
+                };
+                comparisons.push(Comparison {
+                    year: bench.year,
+                    day: bench.day,
+                    variant: bench.variant.clone(),
+                    name,
+                    baseline: base_stats.median,
+                    current: stats.median,
+                    change,
+                    regression: change > threshold,
+                });
+            }
+        }
+        comparisons
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub variant: String,
+    /// parse, part 1 or part 2
+    pub name: String,
+    /// median in nanoseconds
+    pub baseline: u64,
+    pub current: u64,
+    /// relative change of the median, 0.25 is 25% slower
+    pub change: f64,
+    pub regression: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::solution::Solution;
+
+    struct Count;
+    impl Solution for Count {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 2;
+
+        type Input<'a> = Vec<&'a str>;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+            input.lines().collect()
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+            input.len()
+        }
+
+        fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+            input.iter().map(|line| line.len()).sum()
+        }
+    }
+
+    fn stats(median: u64) -> Stats {
+        Stats {
+            min: median,
+            median,
+            mean: median,
+            stddev: 0,
+        }
+    }
+
+    #[test]
+    fn statistics() {
+        let samples: Vec<Duration> = [4, 2, 9, 5]
+            .iter()
+            .map(|n| Duration::from_nanos(*n))
+            .collect();
+        let stats = Stats::new(&samples);
+        assert_eq!(stats.min, 2);
+        assert_eq!(stats.median, 4);
+        assert_eq!(stats.mean, 5);
+        // variance is (1 + 9 + 16 + 0) / 4 = 6.5
+        assert_eq!(stats.stddev, 3);
+        assert_eq!(Stats::new(&samples[..3]).median, 4);
+    }
+
+    #[test]
+    fn measure_and_save() {
+        let bench = DayBench::measure(&Count, "ab\ncde", &Variant::Example, &Part::ALL, 5);
+        assert_eq!(bench.parts.len(), 2);
+        assert_eq!(bench.parts[1].answer, "5");
+        assert!(bench.parse.min <= bench.parse.median);
+
+        let report = Report {
+            runs: 5,
+            days: vec![bench],
+        };
+        let path = env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        report.save(&path).unwrap();
+        assert_eq!(Report::load(&path).unwrap(), report);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn compare_with_baseline() {
+        let day = |parse, part_1| DayBench {
+            year: 2000,
+            day: 2,
+            variant: "real".to_string(),
+            parse: stats(parse),
+            parts: vec![PartBench {
+                part: 1,
+                answer: "1".to_string(),
+                stats: stats(part_1),
+            }],
+        };
+        let baseline = Report {
+            runs: 1,
+            days: vec![day(100, 1000)],
+        };
+        let current = Report {
+            runs: 1,
+            days: vec![day(105, 1500)],
+        };
+        let comparisons = current.compare(&baseline, 0.1);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regression);
+        assert_eq!(comparisons[1].name, "part 1");
+        assert!(comparisons[1].regression);
+        assert!((comparisons[1].change - 0.5).abs() < 1e-9);
+        assert!(current.compare(&Report::default(), 0.1).is_empty());
+    }
+}
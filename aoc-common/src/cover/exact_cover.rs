@@ -0,0 +1,315 @@
+/// An exact cover problem: pick rows so that every required column is covered
+/// exactly its count times and every optional column at most once.
+///
+/// The solver is Knuth's Algorithm X on arrays instead of linked lists. Rows are
+/// blocked and unblocked with counters, which gives the same cheap undo as
+/// dancing links, and it always branches on the column with the fewest rows left.
+/// A row is picked at most once, a column with a count above one has to be covered
+/// by that many distinct rows, like `count` placements of the same piece.
+///
+/// ```
+/// use aoc_common::cover::ExactCover;
+///
+/// let mut cover = ExactCover::new();
+/// let (a, b, c) = (cover.add_column(), cover.add_column(), cover.add_column());
+/// cover.add_row(&[a, b]);
+/// cover.add_row(&[c]);
+/// cover.add_row(&[a, b, c]);
+/// cover.add_row(&[b]);
+/// assert_eq!(cover.solve(), Some(vec![0, 1]));
+/// assert_eq!(cover.count_solutions(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ExactCover {
+    columns: Vec<Column>,
+    rows: Vec<Vec<usize>>,
+    column_rows: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Column {
+    count: usize,
+    optional: bool,
+}
+
+impl ExactCover {
+    pub fn new() -> Self {
+        ExactCover::default()
+    }
+
+    /// a column that must be covered exactly once
+    pub fn add_column(&mut self) -> usize {
+        self.add_column_with_count(1)
+    }
+
+    /// a column that must be covered exactly `count` times
+    pub fn add_column_with_count(&mut self, count: usize) -> usize {
+        self.push_column(Column {
+            count,
+            optional: false,
+        })
+    }
+
+    /// a column that may be covered once, or not at all
+    pub fn add_optional_column(&mut self) -> usize {
+        self.push_column(Column {
+            count: 1,
+            optional: true,
+        })
+    }
+
+    fn push_column(&mut self, column: Column) -> usize {
+        self.columns.push(column);
+        self.column_rows.push(vec![]);
+        self.columns.len() - 1
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Adds a row that covers the columns, returns its index. Panics on a
+    /// column that was not added.
+    pub fn add_row(&mut self, columns: &[usize]) -> usize {
+        let row = self.rows.len();
+        let mut columns = columns.to_vec();
+        columns.sort_unstable();
+        columns.dedup();
+        for column in columns.iter() {
+            assert!(*column < self.columns.len(), "unknown column {}", column);
+            self.column_rows[*column].push(row);
+        }
+        self.rows.push(columns);
+        row
+    }
+
+    pub fn row(&self, row: usize) -> &[usize] {
+        &self.rows[row]
+    }
+
+    /// the rows of the first solution that is found
+    pub fn solve(&self) -> Option<Vec<usize>> {
+        let mut solution = None;
+        self.search(|rows| {
+            solution = Some(rows.to_vec());
+            false
+        });
+        solution
+    }
+
+    pub fn count_solutions(&self) -> usize {
+        let mut count = 0;
+        self.search(|_| {
+            count += 1;
+            true
+        });
+        count
+    }
+
+    /// Calls `visit` with the rows of every solution, until it returns false.
+    /// Every set of rows is found once, in the order they were picked.
+    pub fn search(&self, mut visit: impl FnMut(&[usize]) -> bool) {
+        let mut solver = Solver {
+            cover: self,
+            remaining: self.columns.iter().map(|column| column.count).collect(),
+            available: self.column_rows.iter().map(|rows| rows.len()).collect(),
+            blocked: vec![0; self.rows.len()],
+            chosen: vec![],
+        };
+        // rows on a column that is not needed at all can never be picked
+        for column in 0..self.columns.len() {
+            if solver.remaining[column] == 0 {
+                for row in self.column_rows[column].iter() {
+                    solver.block(*row);
+                }
+            }
+        }
+        solver.run(&mut visit);
+    }
+}
+
+struct Solver<'a> {
+    cover: &'a ExactCover,
+    /// how many more times every column can be covered
+    remaining: Vec<usize>,
+    /// the rows of every column that are not blocked
+    available: Vec<usize>,
+    /// a row can be picked while nothing blocks it
+    blocked: Vec<usize>,
+    chosen: Vec<usize>,
+}
+
+impl Solver<'_> {
+    fn block(&mut self, row: usize) {
+        self.blocked[row] += 1;
+        if self.blocked[row] == 1 {
+            for column in self.cover.rows[row].iter() {
+                self.available[*column] -= 1;
+            }
+        }
+    }
+
+    fn unblock(&mut self, row: usize) {
+        self.blocked[row] -= 1;
+        if self.blocked[row] == 0 {
+            for column in self.cover.rows[row].iter() {
+                self.available[*column] += 1;
+            }
+        }
+    }
+
+    fn select(&mut self, row: usize) {
+        let cover = self.cover;
+        self.block(row);
+        for column in cover.rows[row].iter() {
+            self.remaining[*column] -= 1;
+            if self.remaining[*column] == 0 {
+                for other in cover.column_rows[*column].iter() {
+                    self.block(*other);
+                }
+            }
+        }
+        self.chosen.push(row);
+    }
+
+    fn deselect(&mut self, row: usize) {
+        let cover = self.cover;
+        self.chosen.pop();
+        for column in cover.rows[row].iter().rev() {
+            if self.remaining[*column] == 0 {
+                for other in cover.column_rows[*column].iter().rev() {
+                    self.unblock(*other);
+                }
+            }
+            self.remaining[*column] += 1;
+        }
+        self.unblock(row);
+    }
+
+    /// false once the visitor wants to stop
+    fn run(&mut self, visit: &mut impl FnMut(&[usize]) -> bool) -> bool {
+        let cover = self.cover;
+        let mut branch: Option<usize> = None;
+        for (column, definition) in cover.columns.iter().enumerate() {
+            if definition.optional || self.remaining[column] == 0 {
+                continue;
+            }
+            if self.available[column] < self.remaining[column] {
+                return true;
+            }
+            if branch.is_none_or(|best| self.available[column] < self.available[best]) {
+                branch = Some(column);
+            }
+        }
+        let Some(column) = branch else {
+            return visit(&self.chosen);
+        };
+
+        // once a row has been tried for a column that takes several rows, the
+        // other branches leave it out, so the copies are only picked in one order
+        let several = self.remaining[column] > 1;
+        let mut tried = vec![];
+        let mut keep_going = true;
+        for row in cover.column_rows[column].iter().copied() {
+            if self.blocked[row] > 0 {
+                continue;
+            }
+            self.select(row);
+            keep_going = self.run(visit);
+            self.deselect(row);
+            if !keep_going {
+                break;
+            }
+            if several {
+                self.block(row);
+                tried.push(row);
+            }
+        }
+        for row in tried.into_iter().rev() {
+            self.unblock(row);
+        }
+        keep_going
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Knuth's example from the dancing links paper
+    fn knuth() -> ExactCover {
+        let mut cover = ExactCover::new();
+        for _ in 0..7 {
+            cover.add_column();
+        }
+        for row in [
+            vec![2, 4, 5],
+            vec![0, 3, 6],
+            vec![1, 2, 5],
+            vec![0, 3],
+            vec![1, 6],
+            vec![3, 4, 6],
+        ] {
+            cover.add_row(&row);
+        }
+        cover
+    }
+
+    #[test]
+    fn single_solution() {
+        let cover = knuth();
+        let mut solution = cover.solve().unwrap();
+        solution.sort();
+        assert_eq!(solution, vec![0, 3, 4]);
+        assert_eq!(cover.count_solutions(), 1);
+
+        let mut impossible = knuth();
+        impossible.add_column();
+        assert_eq!(impossible.solve(), None);
+    }
+
+    #[test]
+    fn optional_columns() {
+        let mut cover = ExactCover::new();
+        let (a, b) = (cover.add_column(), cover.add_column());
+        let optional = cover.add_optional_column();
+        cover.add_row(&[a, optional]);
+        cover.add_row(&[b, optional]);
+        cover.add_row(&[a]);
+        cover.add_row(&[b]);
+        // the optional column can not be used twice
+        assert_eq!(cover.count_solutions(), 3);
+    }
+
+    #[test]
+    fn column_counts() {
+        // two dominoes and a monomino on a row of five cells
+        let mut cover = ExactCover::new();
+        let cells: Vec<usize> = (0..5).map(|_| cover.add_column()).collect();
+        let domino = cover.add_column_with_count(2);
+        let monomino = cover.add_column();
+        for x in 0..4 {
+            cover.add_row(&[domino, cells[x], cells[x + 1]]);
+        }
+        for cell in cells.iter() {
+            cover.add_row(&[monomino, *cell]);
+        }
+        // the monomino goes at either end or in the middle
+        assert_eq!(cover.count_solutions(), 3);
+
+        let mut none = ExactCover::new();
+        let nothing = none.add_column_with_count(0);
+        none.add_row(&[nothing]);
+        assert_eq!(none.solve(), Some(vec![]));
+
+        // a single row can not cover a column twice
+        let mut once = ExactCover::new();
+        let twice = once.add_column_with_count(2);
+        once.add_row(&[twice]);
+        assert_eq!(once.solve(), None);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod exact_cover;
+pub mod polyomino;
+
+pub use exact_cover::ExactCover;
+pub use polyomino::{Orientations, Packing, Placement, Shape};
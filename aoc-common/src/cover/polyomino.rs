@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+
+use super::exact_cover::ExactCover;
+use crate::grid::{DenseGrid, Grid, Position, Transform};
+
+/// The orientations a piece may be placed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientations {
+    Fixed,
+    /// the four rotations, no mirrors
+    Rotations,
+    /// rotations and mirrors
+    All,
+}
+
+/// A set of cells, moved so the top left of its bounding box is at (0, 0).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shape {
+    cells: Vec<Position>,
+}
+
+impl Shape {
+    pub fn new(cells: impl IntoIterator<Item = Position>) -> Self {
+        let mut cells: Vec<Position> = cells.into_iter().collect();
+        let min_x = cells.iter().map(|cell| cell.x).min().unwrap_or(0);
+        let min_y = cells.iter().map(|cell| cell.y).min().unwrap_or(0);
+        for cell in cells.iter_mut() {
+            *cell = Position::new(cell.x - min_x, cell.y - min_y);
+        }
+        cells.sort_by_key(|cell| (cell.y, cell.x));
+        cells.dedup();
+        Shape { cells }
+    }
+
+    /// `#` marks a cell, any other character is empty
+    pub fn parse(text: &str) -> Self {
+        Shape::new(text.lines().enumerate().flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(x, _)| Position::new(x as isize, y as isize))
+        }))
+    }
+
+    /// row by row
+    pub fn cells(&self) -> &[Position] {
+        &self.cells
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn to_grid(&self) -> DenseGrid<bool> {
+        let width = self.cells.iter().map(|cell| cell.x + 1).max().unwrap_or(0);
+        let height = self.cells.iter().map(|cell| cell.y + 1).max().unwrap_or(0);
+        let mut cells = vec![false; (width * height) as usize];
+        for cell in self.cells.iter() {
+            cells[(cell.y * width + cell.x) as usize] = true;
+        }
+        DenseGrid::from_vec(width as usize, cells)
+    }
+
+    /// the distinct orientations, the shape itself first
+    pub fn orientations(&self, allowed: Orientations) -> Vec<Shape> {
+        let transforms = match allowed {
+            Orientations::Fixed => &Transform::ALL[..1],
+            Orientations::Rotations => &Transform::ALL[..4],
+            Orientations::All => &Transform::ALL[..],
+        };
+        let grid = self.to_grid();
+        let mut shapes: Vec<Shape> = vec![];
+        for transform in transforms {
+            let variant = grid.transformed(*transform);
+            let shape = Shape::new(
+                variant
+                    .iter()
+                    .filter(|(_, filled)| **filled)
+                    .map(|(position, _)| position),
+            );
+            if !shapes.contains(&shape) {
+                shapes.push(shape);
+            }
+        }
+        shapes
+    }
+}
+
+/// Where one copy of a piece ended up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    /// the index of the piece in the order they were added
+    pub piece: usize,
+    pub cells: Vec<Position>,
+}
+
+#[derive(Debug, Clone)]
+struct Piece {
+    shape: Shape,
+    count: usize,
+    orientations: Orientations,
+}
+
+/// Fits a number of copies of every piece into a region without overlaps.
+/// Cells of the region may stay empty unless `without_gaps` is used.
+///
+/// ```
+/// use aoc_common::cover::{Orientations, Packing, Shape};
+///
+/// let domino = Shape::parse("##");
+/// let packing = Packing::rectangle(3, 2).with_piece(domino.clone(), 3, Orientations::All);
+/// assert_eq!(packing.solve().map(|placements| placements.len()), Some(3));
+///
+/// let crowded = Packing::rectangle(3, 2).with_piece(domino, 4, Orientations::All);
+/// assert_eq!(crowded.solve(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Packing {
+    region: Vec<Position>,
+    pieces: Vec<Piece>,
+    gaps: bool,
+}
+
+impl Packing {
+    pub fn new(region: impl IntoIterator<Item = Position>) -> Self {
+        let mut region: Vec<Position> = region.into_iter().collect();
+        region.sort_by_key(|cell| (cell.y, cell.x));
+        region.dedup();
+        Packing {
+            region,
+            pieces: vec![],
+            gaps: true,
+        }
+    }
+
+    pub fn rectangle(width: usize, height: usize) -> Self {
+        Packing::new(
+            (0..height as isize)
+                .flat_map(|y| (0..width as isize).map(move |x| Position::new(x, y))),
+        )
+    }
+
+    /// `count` copies of the shape
+    pub fn with_piece(mut self, shape: Shape, count: usize, orientations: Orientations) -> Self {
+        self.pieces.push(Piece {
+            shape,
+            count,
+            orientations,
+        });
+        self
+    }
+
+    /// every cell of the region has to be covered
+    pub fn without_gaps(mut self) -> Self {
+        self.gaps = false;
+        self
+    }
+
+    /// the cells the pieces cover together
+    pub fn required_cells(&self) -> usize {
+        self.pieces
+            .iter()
+            .map(|piece| piece.count * piece.shape.len())
+            .sum()
+    }
+
+    /// The cells are the columns of an exact cover, with a column for every
+    /// piece that takes its count. Gaps are filled with single cell blanks.
+    fn exact_cover(&self) -> Option<(ExactCover, Vec<Option<Placement>>)> {
+        let required = self.required_cells();
+        if required > self.region.len() || (!self.gaps && required < self.region.len()) {
+            return None;
+        }
+        let mut cover = ExactCover::new();
+        let cells: HashMap<Position, usize> = self
+            .region
+            .iter()
+            .map(|cell| (*cell, cover.add_column()))
+            .collect();
+        let mut placements = vec![];
+
+        for (index, piece) in self.pieces.iter().enumerate() {
+            if piece.count == 0 || piece.shape.is_empty() {
+                continue;
+            }
+            let column = cover.add_column_with_count(piece.count);
+            for shape in piece.shape.orientations(piece.orientations) {
+                let first = shape.cells()[0];
+                for anchor in self.region.iter() {
+                    let placed: Vec<Position> = shape
+                        .cells()
+                        .iter()
+                        .map(|cell| {
+                            Position::new(anchor.x + cell.x - first.x, anchor.y + cell.y - first.y)
+                        })
+                        .collect();
+                    let Some(mut columns) = placed
+                        .iter()
+                        .map(|cell| cells.get(cell).copied())
+                        .collect::<Option<Vec<usize>>>()
+                    else {
+                        continue;
+                    };
+                    columns.push(column);
+                    cover.add_row(&columns);
+                    placements.push(Some(Placement {
+                        piece: index,
+                        cells: placed,
+                    }));
+                }
+            }
+        }
+
+        let blanks = self.region.len() - required;
+        if blanks > 0 {
+            let column = cover.add_column_with_count(blanks);
+            for cell in self.region.iter() {
+                cover.add_row(&[column, cells[cell]]);
+                placements.push(None);
+            }
+        }
+        Some((cover, placements))
+    }
+
+    /// the placement of every copy of every piece, None when they do not fit
+    pub fn solve(&self) -> Option<Vec<Placement>> {
+        let (cover, mut placements) = self.exact_cover()?;
+        let rows = cover.solve()?;
+        Some(
+            rows.into_iter()
+                .filter_map(|row| placements[row].take())
+                .collect(),
+        )
+    }
+
+    pub fn fits(&self) -> bool {
+        self.exact_cover()
+            .is_some_and(|(cover, _)| cover.solve().is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shape_orientations() {
+        let l = Shape::parse("#.\n#.\n##");
+        assert_eq!(l.len(), 4);
+        assert_eq!(l.orientations(Orientations::Fixed), vec![l.clone()]);
+        assert_eq!(l.orientations(Orientations::Rotations).len(), 4);
+        assert_eq!(l.orientations(Orientations::All).len(), 8);
+        assert_eq!(
+            Shape::parse("##\n##").orientations(Orientations::All).len(),
+            1
+        );
+        assert_eq!(
+            Shape::parse(".#\n##").cells(),
+            Shape::new([
+                Position::new(5, 4),
+                Position::new(4, 5),
+                Position::new(5, 5)
+            ])
+            .cells()
+        );
+    }
+
+    #[test]
+    fn pack_region() {
+        // the example presents of 2025 day 12
+        let presents = [
+            "###\n##.\n##.",
+            "###\n##.\n.##",
+            ".##\n###\n##.",
+            "##.\n###\n##.",
+            "###\n#..\n###",
+            "###\n.#.\n###",
+        ]
+        .map(Shape::parse);
+        let packing = |width, height, counts: [usize; 6]| {
+            presents.iter().zip(counts).fold(
+                Packing::rectangle(width, height),
+                |packing, (shape, count)| {
+                    packing.with_piece(shape.clone(), count, Orientations::All)
+                },
+            )
+        };
+
+        let placements = packing(4, 4, [0, 0, 0, 0, 2, 0]).solve().unwrap();
+        assert_eq!(placements.len(), 2);
+        assert!(placements.iter().all(|placement| placement.piece == 4));
+        let mut covered: Vec<Position> = placements.into_iter().flat_map(|p| p.cells).collect();
+        covered.sort_by_key(|cell| (cell.y, cell.x));
+        covered.dedup();
+        assert_eq!(covered.len(), 14);
+
+        assert!(packing(12, 5, [1, 0, 1, 0, 2, 2]).fits());
+        assert!(!packing(12, 5, [1, 0, 1, 0, 3, 2]).fits());
+    }
+
+    #[test]
+    fn without_gaps() {
+        let tromino = Shape::parse("##\n#.");
+        let packing = Packing::rectangle(3, 2).with_piece(tromino.clone(), 2, Orientations::All);
+        assert!(packing.clone().without_gaps().fits());
+        let fixed = Packing::rectangle(3, 2)
+            .with_piece(tromino.clone(), 2, Orientations::Fixed)
+            .without_gaps();
+        assert!(!fixed.fits());
+        let too_few = Packing::rectangle(3, 3).with_piece(tromino, 2, Orientations::All);
+        assert!(too_few.fits());
+        assert!(!too_few.without_gaps().fits());
+    }
+}
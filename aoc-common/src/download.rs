@@ -0,0 +1,304 @@
+use std::{
+    cell::Cell,
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::input::{InputResolver, Variant};
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum HttpError {
+    /// the server answered, but not with a 2xx status
+    Status {
+        status: u16,
+        body: String,
+    },
+    Transport(String),
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Status { status, body } => {
+                write!(f, "http status {}: {}", status, body.trim())
+            }
+            HttpError::Transport(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for HttpError {}
+
+/// The requests the tooling makes, authenticated with the session cookie.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<String, HttpError>;
+    /// posts an url encoded form
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, HttpError>;
+}
+
+impl<H: Http + ?Sized> Http for &H {
+    fn get(&self, url: &str, session: &str) -> Result<String, HttpError> {
+        (**self).get(url, session)
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, HttpError> {
+        (**self).post(url, session, form)
+    }
+}
+
+/// The session token from `AOC_SESSION`, or else from `~/.config/aoc/session`.
+pub fn session_token() -> Option<String> {
+    let token = match env::var(SESSION_ENV) {
+        Ok(token) => token,
+        Err(_) => {
+            let home = env::var_os("HOME")?;
+            fs::read_to_string(Path::new(&home).join(".config/aoc/session")).ok()?
+        }
+    };
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+/// Keeps at least `min_interval` between requests. With a state file the
+/// time of the last request is shared between runs of the tooling.
+pub struct Throttle {
+    min_interval: Duration,
+    state_file: Option<PathBuf>,
+    last_request: Cell<Option<SystemTime>>,
+}
+
+impl Throttle {
+    pub fn new(min_interval: Duration) -> Self {
+        Throttle {
+            min_interval,
+            state_file: None,
+            last_request: Cell::new(None),
+        }
+    }
+
+    pub fn with_state_file(mut self, state_file: impl Into<PathBuf>) -> Self {
+        self.state_file = Some(state_file.into());
+        self
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let stored = self.state_file.as_ref().and_then(|file| {
+            let millis = fs::read_to_string(file).ok()?.trim().parse().ok()?;
+            Some(UNIX_EPOCH + Duration::from_millis(millis))
+        });
+        stored.max(self.last_request.get())
+    }
+
+    /// blocks until the next request is allowed and claims it
+    pub fn wait(&self) {
+        if let Some(last_request) = self.last_request() {
+            let elapsed = SystemTime::now()
+                .duration_since(last_request)
+                .unwrap_or_default();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        let now = SystemTime::now();
+        self.last_request.set(Some(now));
+        if let Some(file) = &self.state_file {
+            let millis = now
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            // losing the state only means a shorter wait next run
+            let _ = fs::create_dir_all(file.parent().unwrap_or(Path::new(".")))
+                .and_then(|_| fs::write(file, millis.to_string()));
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DownloadError {
+    Http(HttpError),
+    Write { path: PathBuf, source: io::Error },
+}
+
+impl Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DownloadError::Http(error) => write!(f, "download failed, {}", error),
+            DownloadError::Write { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for DownloadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DownloadError::Http(error) => Some(error),
+            DownloadError::Write { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Downloads real inputs into the input directory of the resolver.
+/// An input that is already there is never downloaded again.
+pub struct Downloader<H> {
+    http: H,
+    resolver: InputResolver,
+    session: String,
+    base_url: String,
+    throttle: Throttle,
+}
+
+impl<H: Http> Downloader<H> {
+    pub fn new(http: H, resolver: InputResolver, session: impl Into<String>) -> Self {
+        let throttle = Throttle::new(Duration::from_secs(5))
+            .with_state_file(resolver.input_dir().join(".last-request"));
+        Downloader {
+            http,
+            resolver,
+            session: session.into(),
+            base_url: BASE_URL.to_string(),
+            throttle,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn with_throttle(mut self, throttle: Throttle) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.resolver.input_dir_path(year, day, &Variant::Real)
+    }
+
+    pub fn is_cached(&self, year: u16, day: u8) -> bool {
+        self.cache_path(year, day).is_file()
+    }
+
+    /// path of the cached input, downloading it first when needed
+    pub fn fetch(&self, year: u16, day: u8) -> Result<PathBuf, DownloadError> {
+        let path = self.cache_path(year, day);
+        if path.is_file() {
+            return Ok(path);
+        }
+        self.throttle.wait();
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let input = self
+            .http
+            .get(&url, &self.session)
+            .map_err(DownloadError::Http)?;
+
+        let write = |path: &Path| {
+            fs::create_dir_all(path.parent().unwrap())?;
+            // write next to the target first, a half written input must never look cached
+            let partial = path.with_extension("partial");
+            fs::write(&partial, input)?;
+            fs::rename(&partial, path)
+        };
+        write(&path).map_err(|source| DownloadError::Write {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, time::Instant};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct FakeHttp {
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl Http for FakeHttp {
+        fn get(&self, url: &str, session: &str) -> Result<String, HttpError> {
+            self.requests.borrow_mut().push(url.to_string());
+            match session {
+                "valid" => Ok("1\n2\n3\n".to_string()),
+                _ => Err(HttpError::Status {
+                    status: 400,
+                    body: "log in".to_string(),
+                }),
+            }
+        }
+
+        fn post(&self, _: &str, _: &str, _: &[(&str, &str)]) -> Result<String, HttpError> {
+            unreachable!("inputs are only downloaded")
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-download-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once() {
+        let dir = temp_dir("once");
+        let http = FakeHttp::default();
+        let downloader = Downloader::new(&http, InputResolver::new(&dir), "valid")
+            .with_base_url("http://localhost")
+            .with_throttle(Throttle::new(Duration::ZERO));
+
+        let path = downloader.fetch(2020, 1).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+        assert_eq!(downloader.fetch(2020, 1).unwrap(), path);
+        assert_eq!(
+            *http.requests.borrow(),
+            vec!["http://localhost/2020/day/1/input"]
+        );
+        assert_eq!(
+            InputResolver::new(&dir)
+                .locate(2020, 1, &Variant::Real)
+                .unwrap(),
+            path
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let dir = temp_dir("failed");
+        let http = FakeHttp::default();
+        let downloader = Downloader::new(&http, InputResolver::new(&dir), "expired")
+            .with_throttle(Throttle::new(Duration::ZERO));
+        assert!(matches!(
+            downloader.fetch(2020, 2),
+            Err(DownloadError::Http(HttpError::Status { status: 400, .. }))
+        ));
+        assert!(!downloader.is_cached(2020, 2));
+    }
+
+    #[test]
+    fn throttle_spaces_requests() {
+        let dir = temp_dir("throttle");
+        let state_file = dir.join(".last-request");
+        Throttle::new(Duration::from_millis(50))
+            .with_state_file(&state_file)
+            .wait();
+
+        // a new throttle, like a second run of the tooling, still has to wait
+        let start = Instant::now();
+        Throttle::new(Duration::from_millis(50))
+            .with_state_file(&state_file)
+            .wait();
+        assert!(start.elapsed() >= Duration::from_millis(40));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,5 @@
+pub mod polygon;
+pub mod segment;
+
+pub use polygon::{CompressedPolygon, Compression, Polygon};
+pub use segment::{Crossing, Intersection, Rational, RationalPoint, Segment, sweep_intersections};
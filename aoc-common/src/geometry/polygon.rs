@@ -0,0 +1,385 @@
+use std::ops::Range;
+
+use super::segment::{Intersection, Segment, sweep_intersections};
+use crate::grid::{DenseGrid, Grid, Position, flood_fill};
+
+/// A closed polygon on the integer lattice, the last vertex connects back to
+/// the first one. The vertices are the centers of grid cells, like the corners
+/// of a dug out lagoon or the red tiles of a floor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Position>,
+}
+
+impl Polygon {
+    pub fn new(vertices: impl IntoIterator<Item = Position>) -> Self {
+        Polygon {
+            vertices: vertices.into_iter().collect(),
+        }
+    }
+
+    pub fn vertices(&self) -> &[Position] {
+        &self.vertices
+    }
+
+    /// every side as a pair of vertices, the closing side last
+    pub fn edges(&self) -> impl Iterator<Item = (Position, Position)> + '_ {
+        let count = self.vertices.len();
+        (0..count).map(move |index| (self.vertices[index], self.vertices[(index + 1) % count]))
+    }
+
+    /// true when every side is horizontal or vertical
+    pub fn is_rectilinear(&self) -> bool {
+        self.edges().all(|(a, b)| a.x == b.x || a.y == b.y)
+    }
+
+    /// True when the sides only meet their neighbours, at the vertex they
+    /// share. The formulas below assume this.
+    pub fn is_simple(&self) -> bool {
+        let count = self.vertices.len();
+        if count < 3 {
+            return false;
+        }
+        let sides: Vec<Segment> = self.edges().map(|(a, b)| Segment::new(a, b)).collect();
+        sweep_intersections(&sides).iter().all(|crossing| {
+            let neighbours = crossing.second == crossing.first + 1
+                || (crossing.first == 0 && crossing.second == count - 1);
+            let shared = match crossing.second == crossing.first + 1 {
+                true => self.vertices[crossing.second],
+                false => self.vertices[0],
+            };
+            neighbours && crossing.intersection == Intersection::Point(shared.into())
+        })
+    }
+
+    /// The shoelace formula, twice the area so it stays an integer. Any
+    /// orientation gives the same value.
+    pub fn double_area(&self) -> usize {
+        self.edges()
+            .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+            .sum::<i128>()
+            .unsigned_abs() as usize
+    }
+
+    /// the area enclosed by the lines between the vertices, rounded down
+    pub fn area(&self) -> usize {
+        self.double_area() / 2
+    }
+
+    /// the lattice points on the sides
+    pub fn boundary_points(&self) -> usize {
+        self.edges()
+            .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)))
+            .sum()
+    }
+
+    /// the lattice points strictly inside, with Pick's theorem
+    pub fn interior_points(&self) -> usize {
+        (self.double_area() + 2 - self.boundary_points()) / 2
+    }
+
+    /// the cells on the sides and inside, when every vertex is a cell
+    pub fn cells(&self) -> usize {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// true for points inside or on a side
+    pub fn contains(&self, point: Position) -> bool {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let cross = (b.x - a.x) as i128 * (point.y - a.y) as i128
+                - (b.y - a.y) as i128 * (point.x - a.x) as i128;
+            let between = (a.x.min(b.x)..=a.x.max(b.x)).contains(&point.x)
+                && (a.y.min(b.y)..=a.y.max(b.y)).contains(&point.y);
+            if cross == 0 && between {
+                return true;
+            }
+            // a ray to the right crosses the sides that span the row of the point
+            if (a.y > point.y) != (b.y > point.y) && (cross > 0) == (b.y > a.y) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    /// The polygon on a small grid, see `CompressedPolygon`. None when it is
+    /// not rectilinear or has no vertices.
+    pub fn compress(&self) -> Option<CompressedPolygon> {
+        if self.vertices.is_empty() || !self.is_rectilinear() {
+            return None;
+        }
+        Some(CompressedPolygon::new(self))
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+/// Squeezes a few interesting values out of a huge range. Every value gets a
+/// block of its own and the stretches between them become a single block.
+///
+/// ```
+/// use aoc_common::geometry::Compression;
+///
+/// let xs = Compression::new([2, 7, 11]);
+/// assert_eq!(xs.len(), 5);
+/// assert_eq!(xs.index(7), Some(2));
+/// assert_eq!(xs.index(9), Some(3));
+/// assert_eq!(xs.block(3), 8..11);
+/// assert_eq!(xs.index(12), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compression {
+    /// block i covers breaks[i]..breaks[i + 1]
+    breaks: Vec<isize>,
+}
+
+impl Compression {
+    pub fn new(values: impl IntoIterator<Item = isize>) -> Self {
+        let mut breaks: Vec<isize> = values
+            .into_iter()
+            .flat_map(|value| [value, value + 1])
+            .collect();
+        breaks.sort_unstable();
+        breaks.dedup();
+        Compression { breaks }
+    }
+
+    /// the number of blocks
+    pub fn len(&self) -> usize {
+        self.breaks.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// the block that holds the value, None outside the first and last value
+    pub fn index(&self, value: isize) -> Option<usize> {
+        let index = self.breaks.partition_point(|start| *start <= value);
+        (index > 0 && index < self.breaks.len()).then(|| index - 1)
+    }
+
+    /// the values in the block
+    pub fn block(&self, index: usize) -> Range<isize> {
+        self.breaks[index]..self.breaks[index + 1]
+    }
+
+    pub fn size(&self, index: usize) -> usize {
+        self.breaks[index].abs_diff(self.breaks[index + 1])
+    }
+}
+
+/// A rectilinear polygon on a grid of compressed blocks, so a polygon that
+/// spans billions of cells fits in memory. The cells of a block are either all
+/// covered by the polygon or none of them are, which makes covered areas and
+/// "is this rectangle inside" exact.
+///
+/// ```
+/// use aoc_common::{geometry::Polygon, grid::Position};
+///
+/// // an L shape, the notch at the top right is not covered
+/// let polygon = Polygon::new([(0, 0), (4, 0), (4, 9), (1000, 9), (1000, 20), (0, 20)].map(Position::from));
+/// let compressed = polygon.compress().unwrap();
+/// assert_eq!(compressed.cells(), polygon.cells());
+/// assert!(compressed.contains_rectangle(Position::new(0, 9), Position::new(1000, 20)));
+/// assert!(!compressed.contains_rectangle(Position::new(0, 0), Position::new(5, 20)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompressedPolygon {
+    xs: Compression,
+    ys: Compression,
+    covered: DenseGrid<bool>,
+    /// uncovered blocks above and to the left of every block, with a zero row and column
+    uncovered_sums: DenseGrid<usize>,
+}
+
+impl CompressedPolygon {
+    fn new(polygon: &Polygon) -> Self {
+        // a margin of uncovered blocks around it keeps the outside connected
+        let margin = |values: Vec<isize>| {
+            let min = *values.iter().min().unwrap();
+            let max = *values.iter().max().unwrap();
+            Compression::new(values.into_iter().chain([min - 1, max + 1]))
+        };
+        let xs = margin(polygon.vertices.iter().map(|vertex| vertex.x).collect());
+        let ys = margin(polygon.vertices.iter().map(|vertex| vertex.y).collect());
+
+        let mut sides = DenseGrid::new(xs.len(), ys.len(), false);
+        for (a, b) in polygon.edges() {
+            let (x_start, x_end) = (
+                xs.index(a.x.min(b.x)).unwrap(),
+                xs.index(a.x.max(b.x)).unwrap(),
+            );
+            let (y_start, y_end) = (
+                ys.index(a.y.min(b.y)).unwrap(),
+                ys.index(a.y.max(b.y)).unwrap(),
+            );
+            for y in y_start..=y_end {
+                for x in x_start..=x_end {
+                    sides.set(Position::new(x as isize, y as isize), true);
+                }
+            }
+        }
+        let outside = flood_fill(&sides, Position::new(0, 0), |a, b| !a && !b).unwrap();
+        let covered =
+            DenseGrid::from_fn(sides.boundaries(), |position| !outside.contains(position));
+
+        let mut uncovered_sums = DenseGrid::new(xs.len() + 1, ys.len() + 1, 0);
+        for (position, is_covered) in covered.iter() {
+            let (x, y) = (position.x + 1, position.y + 1);
+            let sum = uncovered_sums[Position::new(x - 1, y)]
+                + uncovered_sums[Position::new(x, y - 1)]
+                - uncovered_sums[Position::new(x - 1, y - 1)]
+                + usize::from(!is_covered);
+            uncovered_sums.set(Position::new(x, y), sum);
+        }
+        CompressedPolygon {
+            xs,
+            ys,
+            covered,
+            uncovered_sums,
+        }
+    }
+
+    pub fn xs(&self) -> &Compression {
+        &self.xs
+    }
+
+    pub fn ys(&self) -> &Compression {
+        &self.ys
+    }
+
+    /// true for the blocks that the polygon covers, sides included
+    pub fn covered(&self) -> &DenseGrid<bool> {
+        &self.covered
+    }
+
+    pub fn contains(&self, point: Position) -> bool {
+        match (self.xs.index(point.x), self.ys.index(point.y)) {
+            (Some(x), Some(y)) => self.covered[Position::new(x as isize, y as isize)],
+            _ => false,
+        }
+    }
+
+    /// the cells the polygon covers, sides included
+    pub fn cells(&self) -> usize {
+        self.covered
+            .iter()
+            .filter(|(_, covered)| **covered)
+            .map(|(position, _)| {
+                self.xs.size(position.x as usize) * self.ys.size(position.y as usize)
+            })
+            .sum()
+    }
+
+    /// true when every cell of the rectangle between the two corners is
+    /// covered, in constant time with the prefix sums
+    pub fn contains_rectangle(&self, a: Position, b: Position) -> bool {
+        let (Some(x_start), Some(x_end)) =
+            (self.xs.index(a.x.min(b.x)), self.xs.index(a.x.max(b.x)))
+        else {
+            return false;
+        };
+        let (Some(y_start), Some(y_end)) =
+            (self.ys.index(a.y.min(b.y)), self.ys.index(a.y.max(b.y)))
+        else {
+            return false;
+        };
+        let sum = |x: usize, y: usize| self.uncovered_sums[Position::new(x as isize, y as isize)];
+        let uncovered = sum(x_end + 1, y_end + 1) + sum(x_start, y_start)
+            - sum(x_start, y_end + 1)
+            - sum(x_end + 1, y_start);
+        uncovered == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// the dig plan of the 2023 day 18 example
+    fn lagoon() -> Polygon {
+        let plan = "R6 D5 L2 D2 R2 D2 L5 U2 L1 U2 R2 U3 L2 U2";
+        let mut position = Position::new(0, 0);
+        Polygon::new(plan.split(' ').map(|step| {
+            let length: isize = step[1..].parse().unwrap();
+            let (x, y) = match &step[..1] {
+                "R" => (length, 0),
+                "L" => (-length, 0),
+                "D" => (0, length),
+                _ => (0, -length),
+            };
+            position = Position::new(position.x + x, position.y + y);
+            position
+        }))
+    }
+
+    #[test]
+    fn shoelace_and_pick() {
+        let lagoon = lagoon();
+        assert!(lagoon.is_rectilinear());
+        assert_eq!(lagoon.area(), 42);
+        assert_eq!(lagoon.boundary_points(), 38);
+        assert_eq!(lagoon.interior_points(), 24);
+        assert_eq!(lagoon.cells(), 62);
+
+        let triangle = Polygon::new([(0, 0), (4, 0), (0, 3)].map(Position::from));
+        assert!(!triangle.is_rectilinear());
+        assert_eq!(triangle.double_area(), 12);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 3);
+        assert!(triangle.compress().is_none());
+    }
+
+    #[test]
+    fn simple_polygons() {
+        assert!(lagoon().is_simple());
+        assert!(Polygon::new([(0, 0), (4, 0), (0, 3)].map(Position::from)).is_simple());
+        // a bow tie crosses itself, the other one folds back over a side
+        let bow_tie = Polygon::new([(0, 0), (4, 4), (4, 0), (0, 4)].map(Position::from));
+        assert!(!bow_tie.is_simple());
+        let folded = Polygon::new([(0, 0), (4, 0), (2, 0), (2, 3)].map(Position::from));
+        assert!(!folded.is_simple());
+        let touching = Polygon::new([(0, 0), (4, 0), (4, 4), (2, 0), (0, 4)].map(Position::from));
+        assert!(!touching.is_simple());
+    }
+
+    #[test]
+    fn point_in_polygon() {
+        let lagoon = lagoon();
+        assert!(lagoon.contains(Position::new(1, 1)));
+        assert!(lagoon.contains(Position::new(6, 3)));
+        assert!(lagoon.contains(Position::new(0, 0)));
+        assert!(lagoon.contains(Position::new(0, 6)));
+        // the notch on the left
+        assert!(!lagoon.contains(Position::new(1, 3)));
+        assert!(!lagoon.contains(Position::new(7, 3)));
+        let triangle = Polygon::new([(0, 0), (4, 0), (0, 3)].map(Position::from));
+        assert!(triangle.contains(Position::new(1, 1)));
+        assert!(triangle.contains(Position::new(2, 1)));
+        assert!(!triangle.contains(Position::new(3, 1)));
+    }
+
+    #[test]
+    fn compressed_polygon() {
+        let lagoon = lagoon();
+        let compressed = lagoon.compress().unwrap();
+        assert_eq!(compressed.cells(), 62);
+        for y in -1..=10 {
+            for x in -2..=8 {
+                let position = Position::new(x, y);
+                assert_eq!(compressed.contains(position), lagoon.contains(position));
+            }
+        }
+        assert!(compressed.contains_rectangle(Position::new(2, 0), Position::new(6, 4)));
+        assert!(!compressed.contains_rectangle(Position::new(0, 0), Position::new(6, 4)));
+        assert!(!compressed.contains_rectangle(Position::new(2, 0), Position::new(6, 6)));
+        assert!(compressed.contains_rectangle(Position::new(2, 5), Position::new(4, 9)));
+        assert!(!compressed.contains_rectangle(Position::new(-5, 0), Position::new(1, 1)));
+    }
+}
@@ -0,0 +1,468 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    fmt::{self, Display},
+};
+
+use crate::grid::Position;
+
+/// An exact fraction, always reduced with a positive denominator so equal
+/// values compare equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    /// panics on a zero denominator
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "zero denominator");
+        let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
+        let sign = denominator.signum();
+        Rational {
+            numerator: sign * numerator / divisor,
+            denominator: sign * denominator / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// None when there is a fractional part
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+}
+
+impl From<isize> for Rational {
+    fn from(value: isize) -> Self {
+        Rational {
+            numerator: value as i128,
+            denominator: 1,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            _ => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    match b {
+        0 => a.max(1),
+        _ => gcd(b, a % b),
+    }
+}
+
+/// A point where two segments cross, it does not have to be on the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RationalPoint {
+    pub x: Rational,
+    pub y: Rational,
+}
+
+impl RationalPoint {
+    /// None when it lies between the grid cells
+    pub fn to_position(&self) -> Option<Position> {
+        Some(Position::new(
+            self.x.to_integer()? as isize,
+            self.y.to_integer()? as isize,
+        ))
+    }
+}
+
+impl From<Position> for RationalPoint {
+    fn from(position: Position) -> Self {
+        RationalPoint {
+            x: position.x.into(),
+            y: position.y.into(),
+        }
+    }
+}
+
+impl Display for RationalPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+/// What two segments have in common.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Intersection {
+    Point(RationalPoint),
+    /// collinear segments that share more than a point, the ends of the
+    /// shared part are always ends of the segments so they are on the grid
+    Overlap(Position, Position),
+}
+
+/// A closed line segment between two grid positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub start: Position,
+    pub end: Position,
+}
+
+fn cross(a: (i128, i128), b: (i128, i128)) -> i128 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+impl Segment {
+    pub fn new(start: Position, end: Position) -> Self {
+        Segment { start, end }
+    }
+
+    fn delta(&self) -> (i128, i128) {
+        (
+            (self.end.x - self.start.x) as i128,
+            (self.end.y - self.start.y) as i128,
+        )
+    }
+
+    /// the vector from our start to the position
+    fn offset(&self, position: Position) -> (i128, i128) {
+        (
+            (position.x - self.start.x) as i128,
+            (position.y - self.start.y) as i128,
+        )
+    }
+
+    pub fn is_point(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    /// the ends ordered by x, then y
+    fn ordered(&self) -> (Position, Position) {
+        let key = |position: &Position| (position.x, position.y);
+        match key(&self.start) <= key(&self.end) {
+            true => (self.start, self.end),
+            false => (self.end, self.start),
+        }
+    }
+
+    /// true for positions on the segment, the ends included
+    pub fn contains(&self, position: Position) -> bool {
+        let (low, high) = self.ordered();
+        cross(self.delta(), self.offset(position)) == 0
+            && (low.x, low.y) <= (position.x, position.y)
+            && (position.x, position.y) <= (high.x, high.y)
+    }
+
+    /// The exact intersection, with integer math only. Touching ends count.
+    pub fn intersection(&self, other: &Segment) -> Option<Intersection> {
+        let point = |position: Position| Some(Intersection::Point(position.into()));
+        if self.is_point() {
+            return other.contains(self.start).then(|| point(self.start))?;
+        }
+        if other.is_point() {
+            return self.contains(other.start).then(|| point(other.start))?;
+        }
+        let (a, b) = (self.delta(), other.delta());
+        let offset = self.offset(other.start);
+        let denominator = cross(a, b);
+        if denominator == 0 {
+            if cross(offset, a) != 0 {
+                return None;
+            }
+            // collinear points are ordered along the line by x, then y
+            let key = |position: &Position| (position.x, position.y);
+            let (low_a, high_a) = self.ordered();
+            let (low_b, high_b) = other.ordered();
+            let low = std::cmp::max_by_key(low_a, low_b, key);
+            let high = std::cmp::min_by_key(high_a, high_b, key);
+            return match key(&low).cmp(&key(&high)) {
+                Ordering::Greater => None,
+                Ordering::Equal => point(low),
+                Ordering::Less => Some(Intersection::Overlap(low, high)),
+            };
+        }
+        // start + t * a == other.start + u * b, with t and u in 0..=1
+        let t = cross(offset, b);
+        let u = cross(offset, a);
+        let within = |value: i128| match denominator > 0 {
+            true => (0..=denominator).contains(&value),
+            false => (denominator..=0).contains(&value),
+        };
+        if !within(t) || !within(u) {
+            return None;
+        }
+        Some(Intersection::Point(RationalPoint {
+            x: Rational::new(self.start.x as i128 * denominator + t * a.0, denominator),
+            y: Rational::new(self.start.y as i128 * denominator + t * a.1, denominator),
+        }))
+    }
+
+    pub fn intersects(&self, other: &Segment) -> bool {
+        self.intersection(other).is_some()
+    }
+}
+
+/// Two segments of a sweep that meet, by their index with `first < second`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crossing {
+    pub first: usize,
+    pub second: usize,
+    pub intersection: Intersection,
+}
+
+/// Every pair of segments that meet, ordered by index.
+///
+/// Horizontal and vertical segments go through a sweep from left to right that
+/// keeps the horizontal ones in an ordered set, so a vertical segment only looks
+/// at the ones it crosses: O((n + k) log n) for k crossings. Collinear segments
+/// are matched per line. Any diagonal segment is checked against all others.
+///
+/// ```
+/// use aoc_common::geometry::{Intersection, Segment, sweep_intersections};
+/// use aoc_common::grid::Position;
+///
+/// let segment = |a: (isize, isize), b: (isize, isize)| Segment::new(a.into(), b.into());
+/// let wires = [segment((0, 5), (8, 5)), segment((3, 0), (3, 9)), segment((6, 4), (6, 20))];
+/// let crossings = sweep_intersections(&wires);
+/// assert_eq!(crossings.len(), 2);
+/// assert_eq!(
+///     crossings[1].intersection,
+///     Intersection::Point(Position::new(6, 5).into())
+/// );
+/// ```
+pub fn sweep_intersections(segments: &[Segment]) -> Vec<Crossing> {
+    let mut pairs: Vec<(usize, usize)> = vec![];
+    let is_diagonal = |segment: &Segment| !segment.is_horizontal() && !segment.is_vertical();
+    // a single point is treated as a horizontal segment
+    let is_vertical = |segment: &Segment| segment.is_vertical() && !segment.is_point();
+
+    for (index, segment) in segments.iter().enumerate() {
+        if is_diagonal(segment) {
+            pairs.extend(
+                (0..segments.len())
+                    .filter(|other| {
+                        *other != index && (!is_diagonal(&segments[*other]) || *other > index)
+                    })
+                    .map(|other| (index, other)),
+            );
+        }
+    }
+
+    // horizontal against vertical; at the same x starts come first and ends last
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    enum Event {
+        Start,
+        Vertical,
+        End,
+    }
+    let mut events: Vec<(isize, Event, usize)> = vec![];
+    for (index, segment) in segments.iter().enumerate() {
+        let (low, high) = segment.ordered();
+        if is_vertical(segment) {
+            events.push((low.x, Event::Vertical, index));
+        } else if segment.is_horizontal() {
+            events.push((low.x, Event::Start, index));
+            events.push((high.x, Event::End, index));
+        }
+    }
+    events.sort();
+    let mut active: BTreeSet<(isize, usize)> = BTreeSet::new();
+    for (_, event, index) in events {
+        let segment = &segments[index];
+        match event {
+            Event::Start => {
+                active.insert((segment.start.y, index));
+            }
+            Event::End => {
+                active.remove(&(segment.start.y, index));
+            }
+            Event::Vertical => {
+                let (low, high) = segment.ordered();
+                pairs.extend(
+                    active
+                        .range((low.y, 0)..=(high.y, usize::MAX))
+                        .map(|(_, horizontal)| (index, *horizontal)),
+                );
+            }
+        }
+    }
+
+    // collinear ones on the same row or column, ordered by where they start
+    let mut lines: Vec<(bool, isize, Position, Position, usize)> = segments
+        .iter()
+        .enumerate()
+        .filter(|(_, segment)| !is_diagonal(segment))
+        .map(|(index, segment)| {
+            let (low, high) = segment.ordered();
+            match is_vertical(segment) {
+                true => (true, low.x, low, high, index),
+                false => (false, low.y, low, high, index),
+            }
+        })
+        .collect();
+    lines.sort_by_key(|(vertical, line, low, _, _)| (*vertical, *line, low.x, low.y));
+    let mut open: Vec<(Position, usize)> = vec![];
+    let mut current = None;
+    for (vertical, line, low, high, index) in lines {
+        if current != Some((vertical, line)) {
+            open.clear();
+            current = Some((vertical, line));
+        }
+        open.retain(|(end, _)| (end.x, end.y) >= (low.x, low.y));
+        pairs.extend(open.iter().map(|(_, other)| (index, *other)));
+        open.push((high, index));
+    }
+
+    let mut crossings: Vec<Crossing> = pairs
+        .into_iter()
+        .filter_map(|(a, b)| {
+            let (first, second) = (a.min(b), a.max(b));
+            let intersection = segments[first].intersection(&segments[second])?;
+            Some(Crossing {
+                first,
+                second,
+                intersection,
+            })
+        })
+        .collect();
+    crossings.sort_by_key(|crossing| (crossing.first, crossing.second));
+    crossings.dedup();
+    crossings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(a: (isize, isize), b: (isize, isize)) -> Segment {
+        Segment::new(a.into(), b.into())
+    }
+
+    fn point(x: isize, y: isize) -> Option<Intersection> {
+        Some(Intersection::Point(Position::new(x, y).into()))
+    }
+
+    #[test]
+    fn rationals() {
+        let half = Rational::new(3, -6);
+        assert_eq!((half.numerator(), half.denominator()), (-1, 2));
+        assert_eq!(half, Rational::new(-2, 4));
+        assert!(half < Rational::from(0));
+        assert_eq!(Rational::new(8, 4).to_integer(), Some(2));
+        assert_eq!(half.to_string(), "-1/2");
+        assert_eq!(Rational::new(0, -3), Rational::from(0));
+    }
+
+    #[test]
+    fn segment_intersections() {
+        // crossing between the grid cells
+        let crossing = segment((0, 0), (3, 1)).intersection(&segment((0, 1), (3, 0)));
+        assert_eq!(
+            crossing,
+            Some(Intersection::Point(RationalPoint {
+                x: Rational::new(3, 2),
+                y: Rational::new(1, 2),
+            }))
+        );
+        assert_eq!(
+            segment((0, 0), (4, 4)).intersection(&segment((4, 0), (0, 4))),
+            point(2, 2)
+        );
+        // touching ends and a point on a segment
+        assert_eq!(
+            segment((0, 0), (2, 0)).intersection(&segment((2, 0), (2, 5))),
+            point(2, 0)
+        );
+        assert_eq!(
+            segment((1, 1), (1, 1)).intersection(&segment((0, 0), (2, 2))),
+            point(1, 1)
+        );
+        assert_eq!(
+            segment((0, 0), (2, 0)).intersection(&segment((3, -1), (3, 1))),
+            None
+        );
+        // parallel and collinear
+        assert_eq!(
+            segment((0, 0), (4, 2)).intersection(&segment((0, 1), (4, 3))),
+            None
+        );
+        assert_eq!(
+            segment((0, 0), (6, 3)).intersection(&segment((8, 4), (2, 1))),
+            Some(Intersection::Overlap(
+                Position::new(2, 1),
+                Position::new(6, 3)
+            ))
+        );
+        assert_eq!(
+            segment((0, 0), (2, 1)).intersection(&segment((2, 1), (4, 2))),
+            point(2, 1)
+        );
+        assert_eq!(
+            segment((0, 0), (2, 1)).intersection(&segment((4, 2), (6, 3))),
+            None
+        );
+    }
+
+    #[test]
+    fn sweep_matches_pairwise() {
+        let segments = vec![
+            segment((0, 0), (10, 0)),
+            segment((5, -3), (5, 3)),
+            segment((0, 0), (0, 8)),
+            segment((8, 0), (14, 0)),
+            segment((2, 4), (12, 4)),
+            segment((12, 4), (12, -2)),
+            segment((0, 8), (12, -4)),
+            segment((3, 4), (3, 4)),
+            segment((20, 20), (21, 20)),
+            segment((5, 2), (5, 6)),
+        ];
+        let mut pairwise = vec![];
+        for first in 0..segments.len() {
+            for second in first + 1..segments.len() {
+                if let Some(intersection) = segments[first].intersection(&segments[second]) {
+                    pairwise.push(Crossing {
+                        first,
+                        second,
+                        intersection,
+                    });
+                }
+            }
+        }
+        assert_eq!(sweep_intersections(&segments), pairwise);
+        assert!(
+            pairwise
+                .iter()
+                .any(|crossing| matches!(crossing.intersection, Intersection::Overlap(_, _)))
+        );
+    }
+}
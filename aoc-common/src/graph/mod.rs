@@ -0,0 +1,130 @@
+pub mod order;
+pub mod paths;
+pub mod union_find;
+
+use std::collections::HashMap;
+
+pub use paths::Distances;
+pub use union_find::{Kruskal, UnionFind, kruskal};
+
+pub type NodeId = usize;
+
+/// Directed graph with named nodes, like the valves or server racks of the puzzles.
+/// Nodes get consecutive ids in the order they are first seen, the algorithms
+/// work on the ids and `name` maps them back.
+#[derive(Debug, Clone)]
+pub struct Graph<W = usize> {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<Vec<(NodeId, W)>>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> Graph<W> {
+    pub fn new() -> Self {
+        Graph {
+            names: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+        }
+    }
+
+    /// the id of the node, added when it is new
+    pub fn node(&mut self, name: &str) -> NodeId {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(vec![]);
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> std::ops::Range<NodeId> {
+        0..self.len()
+    }
+
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: W) {
+        let (from, to) = (self.node(from), self.node(to));
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn add_undirected_edge(&mut self, a: &str, b: &str, weight: W)
+    where
+        W: Clone,
+    {
+        self.add_edge(a, b, weight.clone());
+        self.add_edge(b, a, weight);
+    }
+
+    /// the outgoing edges with their weights
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, W)] {
+        &self.edges[id]
+    }
+
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id].iter().map(|(to, _)| *to)
+    }
+}
+
+impl Graph<usize> {
+    /// Every line is a node followed by the nodes it connects to, like `aaa: bbb ccc`.
+    /// The edges have weight 1.
+    pub fn from_adjacency(input: &str, separator: &str) -> Self {
+        let mut graph = Graph::new();
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let (from, targets) = line.split_once(separator).unwrap_or((line, ""));
+            let from = from.trim();
+            graph.node(from);
+            for to in targets.split([' ', ',']).filter(|to| !to.is_empty()) {
+                graph.add_edge(from, to, 1);
+            }
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_from_names() {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "b", 3);
+        graph.add_undirected_edge("b", "c", 1);
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.id("c"), Some(2));
+        assert_eq!(graph.name(1), "b");
+        assert_eq!(graph.edges(1), &[(2, 1)]);
+        assert_eq!(graph.neighbors(2).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(graph.node("a"), 0);
+        assert_eq!(graph.id("d"), None);
+
+        let graph = Graph::from_adjacency("you: bbb ccc\nbbb: out\nccc: out", ":");
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.edges(graph.id("ccc").unwrap()), &[(3, 1)]);
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::VecDeque;
+
+use super::{Graph, NodeId};
+
+impl<W> Graph<W> {
+    /// Every node before the nodes it has an edge to, None when there is a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<NodeId>> {
+        let mut incoming = vec![0; self.len()];
+        for id in self.nodes() {
+            for to in self.neighbors(id) {
+                incoming[to] += 1;
+            }
+        }
+        let mut queue: VecDeque<NodeId> = self.nodes().filter(|id| incoming[*id] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            for to in self.neighbors(id) {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    queue.push_back(to);
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// The nodes reachable from `from`, every node before the nodes it has an edge to.
+    /// A depth first search, None when a cycle can be reached.
+    pub fn topological_sort_from(&self, from: NodeId) -> Option<Vec<NodeId>> {
+        let mut on_path = vec![false; self.len()];
+        let mut done = vec![false; self.len()];
+        let mut finished = vec![];
+        on_path[from] = true;
+        // the path with the index of the next edge to follow
+        let mut path = vec![(from, 0)];
+        while let Some(&(id, edge)) = path.last() {
+            let Some(&(to, _)) = self.edges(id).get(edge) else {
+                on_path[id] = false;
+                done[id] = true;
+                finished.push(id);
+                path.pop();
+                continue;
+            };
+            path.last_mut().unwrap().1 += 1;
+            if on_path[to] {
+                return None;
+            }
+            if !done[to] {
+                on_path[to] = true;
+                path.push((to, 0));
+            }
+        }
+        finished.reverse();
+        Some(finished)
+    }
+
+    /// The nodes of a cycle in the order of their edges, a depth first search
+    /// that stops at the first edge back to a node on the current path.
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            OnPath,
+            Done,
+        }
+        let mut state = vec![State::New; self.len()];
+        for root in self.nodes() {
+            if state[root] != State::New {
+                continue;
+            }
+            state[root] = State::OnPath;
+            // the path with the index of the next edge to follow
+            let mut path = vec![(root, 0)];
+            while let Some(&(id, edge)) = path.last() {
+                let Some(&(to, _)) = self.edges(id).get(edge) else {
+                    state[id] = State::Done;
+                    path.pop();
+                    continue;
+                };
+                path.last_mut().unwrap().1 += 1;
+                match state[to] {
+                    State::New => {
+                        state[to] = State::OnPath;
+                        path.push((to, 0));
+                    }
+                    State::OnPath => {
+                        let start = path.iter().position(|(id, _)| *id == to).unwrap();
+                        return Some(path[start..].iter().map(|(id, _)| *id).collect());
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// Tarjan's algorithm without recursion. The components come in reverse
+    /// topological order, a component only has edges to components before it.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut index: Vec<Option<usize>> = vec![None; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = vec![];
+        let mut components = vec![];
+        let mut counter = 0;
+        for root in self.nodes() {
+            if index[root].is_some() {
+                continue;
+            }
+            let mut calls = vec![(root, 0)];
+            index[root] = Some(counter);
+            low[root] = counter;
+            counter += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            while let Some(&(id, edge)) = calls.last() {
+                if let Some(&(to, _)) = self.edges(id).get(edge) {
+                    calls.last_mut().unwrap().1 += 1;
+                    match index[to] {
+                        None => {
+                            index[to] = Some(counter);
+                            low[to] = counter;
+                            counter += 1;
+                            stack.push(to);
+                            on_stack[to] = true;
+                            calls.push((to, 0));
+                        }
+                        Some(to_index) if on_stack[to] => low[id] = low[id].min(to_index),
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    low[parent] = low[parent].min(low[id]);
+                }
+                if Some(low[id]) == index[id] {
+                    let mut component = vec![];
+                    loop {
+                        let node = stack.pop().unwrap();
+                        on_stack[node] = false;
+                        component.push(node);
+                        if node == id {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(&str, &str)]) -> Graph {
+        let mut graph = Graph::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to, 1);
+        }
+        graph
+    }
+
+    #[test]
+    fn topological_order() {
+        let graph = graph(&[
+            ("shirt", "tie"),
+            ("tie", "jacket"),
+            ("pants", "shoes"),
+            ("pants", "jacket"),
+        ]);
+        let order = graph.topological_sort().unwrap();
+        let position = |name| {
+            order
+                .iter()
+                .position(|id| *id == graph.id(name).unwrap())
+                .unwrap()
+        };
+        assert!(position("shirt") < position("tie"));
+        assert!(position("tie") < position("jacket"));
+        assert!(position("pants") < position("shoes"));
+        assert!(!graph.has_cycle());
+    }
+
+    #[test]
+    fn cycles() {
+        let graph = graph(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "b")]);
+        assert_eq!(graph.topological_sort(), None);
+        let names: Vec<&str> = graph
+            .find_cycle()
+            .unwrap()
+            .into_iter()
+            .map(|id| graph.name(id))
+            .collect();
+        assert_eq!(names, vec!["b", "c", "d"]);
+
+        let mut self_loop = Graph::new();
+        self_loop.add_edge("a", "a", 1);
+        assert_eq!(self_loop.find_cycle(), Some(vec![0]));
+    }
+
+    #[test]
+    fn reachable_order() {
+        let graph = graph(&[("a", "b"), ("b", "c"), ("a", "c"), ("d", "e"), ("e", "d")]);
+        let names: Vec<&str> = graph
+            .topological_sort_from(graph.id("a").unwrap())
+            .unwrap()
+            .into_iter()
+            .map(|id| graph.name(id))
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+        assert_eq!(graph.topological_sort(), None);
+        assert_eq!(graph.topological_sort_from(graph.id("d").unwrap()), None);
+    }
+
+    #[test]
+    fn components() {
+        let graph = graph(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "d"),
+            ("f", "e"),
+        ]);
+        let mut components: Vec<Vec<&str>> = graph
+            .strongly_connected_components()
+            .into_iter()
+            .map(|component| {
+                let mut names: Vec<&str> = component.into_iter().map(|id| graph.name(id)).collect();
+                names.sort();
+                names
+            })
+            .collect();
+        // d and e come before the components that lead to them
+        assert_eq!(components[0], vec!["d", "e"]);
+        components.sort();
+        assert_eq!(
+            components,
+            vec![vec!["a", "b", "c"], vec!["d", "e"], vec!["f"]]
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use std::{collections::VecDeque, ops::Add};
+
+use super::{Graph, NodeId};
+
+/// Shortest distances between every pair of nodes, None when there is no path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distances<W> {
+    distances: Vec<Vec<Option<W>>>,
+}
+
+impl<W: Copy> Distances<W> {
+    pub fn get(&self, from: NodeId, to: NodeId) -> Option<W> {
+        self.distances[from][to]
+    }
+
+    /// the reachable nodes from `from` with their distance, `from` itself included
+    pub fn from(&self, from: NodeId) -> impl Iterator<Item = (NodeId, W)> + '_ {
+        self.distances[from]
+            .iter()
+            .enumerate()
+            .filter_map(|(to, distance)| Some((to, (*distance)?)))
+    }
+}
+
+impl<W> Graph<W> {
+    /// Number of edges to every node, the weights are ignored.
+    pub fn bfs(&self, from: NodeId) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.len()];
+        distances[from] = Some(0);
+        let mut queue = VecDeque::from([from]);
+        while let Some(id) = queue.pop_front() {
+            let next = distances[id].unwrap() + 1;
+            for to in self.neighbors(id) {
+                if distances[to].is_none() {
+                    distances[to] = Some(next);
+                    queue.push_back(to);
+                }
+            }
+        }
+        distances
+    }
+
+    /// All pairs of unweighted distances with a bfs from every node, O(V * (V + E)).
+    pub fn all_pairs_bfs(&self) -> Distances<usize> {
+        Distances {
+            distances: self.nodes().map(|from| self.bfs(from)).collect(),
+        }
+    }
+
+    /// All pairs of weighted distances, O(V³). Negative cycles are not detected.
+    pub fn floyd_warshall(&self) -> Distances<W>
+    where
+        W: Copy + Ord + Default + Add<Output = W>,
+    {
+        let mut distances = vec![vec![None; self.len()]; self.len()];
+        for from in self.nodes() {
+            distances[from][from] = Some(W::default());
+            for (to, weight) in self.edges(from) {
+                if distances[from][*to].is_none_or(|known| *weight < known) {
+                    distances[from][*to] = Some(*weight);
+                }
+            }
+        }
+        for via in self.nodes() {
+            for from in self.nodes() {
+                let Some(first) = distances[from][via] else {
+                    continue;
+                };
+                for to in self.nodes() {
+                    let Some(second) = distances[via][to] else {
+                        continue;
+                    };
+                    let total = first + second;
+                    if distances[from][to].is_none_or(|known| total < known) {
+                        distances[from][to] = Some(total);
+                    }
+                }
+            }
+        }
+        Distances { distances }
+    }
+
+    /// Number of distinct paths from every node to `to`, counted in topological
+    /// order so every node is only visited once. None when the graph has a cycle.
+    pub fn count_paths_to(&self, to: NodeId) -> Option<Vec<u64>> {
+        Some(self.count_paths_in(self.topological_sort()?, to))
+    }
+
+    /// Only the nodes reachable from `from` are ordered, so a cycle elsewhere
+    /// in the graph does not matter. None when a cycle can be reached.
+    pub fn count_paths(&self, from: NodeId, to: NodeId) -> Option<u64> {
+        Some(self.count_paths_in(self.topological_sort_from(from)?, to)[from])
+    }
+
+    /// paths to `to` for the nodes of `order`, its nodes come before their neighbors
+    fn count_paths_in(&self, order: Vec<NodeId>, to: NodeId) -> Vec<u64> {
+        let mut counts = vec![0; self.len()];
+        counts[to] = 1;
+        for id in order.into_iter().rev().filter(|id| *id != to) {
+            counts[id] = self.neighbors(id).map(|next| counts[next]).sum();
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_pairs() {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "b", 4);
+        graph.add_edge("a", "c", 1);
+        graph.add_edge("c", "b", 2);
+        graph.add_edge("b", "d", 1);
+        let (a, b, d) = (0, 1, 3);
+
+        let weighted = graph.floyd_warshall();
+        assert_eq!(weighted.get(a, b), Some(3));
+        assert_eq!(weighted.get(a, d), Some(4));
+        assert_eq!(weighted.get(d, a), None);
+        assert_eq!(weighted.get(b, b), Some(0));
+
+        let unweighted = graph.all_pairs_bfs();
+        assert_eq!(unweighted.get(a, b), Some(1));
+        assert_eq!(unweighted.get(a, d), Some(2));
+        assert_eq!(unweighted.from(b).collect::<Vec<_>>(), vec![(b, 0), (d, 1)]);
+    }
+
+    #[test]
+    fn count_dag_paths() {
+        let graph = Graph::from_adjacency(
+            "aaa: you hhh\nyou: bbb ccc\nbbb: ddd eee\nccc: ddd eee fff\nddd: ggg\neee: out\nfff: out\nggg: out\nhhh: ccc fff iii\niii: out",
+            ":",
+        );
+        let (you, out) = (graph.id("you").unwrap(), graph.id("out").unwrap());
+        assert_eq!(graph.count_paths(you, out), Some(5));
+        assert_eq!(graph.count_paths(out, you), Some(0));
+
+        let mut cyclic = graph.clone();
+        cyclic.add_edge("out", "you", 1);
+        assert_eq!(cyclic.count_paths(you, out), None);
+    }
+
+    #[test]
+    fn unreachable_cycle() {
+        let mut graph = Graph::from_adjacency("you: bbb ccc\nbbb: out\nccc: out", ":");
+        graph.add_edge("xxx", "yyy", 1);
+        graph.add_edge("yyy", "xxx", 1);
+        graph.add_edge("yyy", "out", 1);
+        let (you, out) = (graph.id("you").unwrap(), graph.id("out").unwrap());
+        assert_eq!(graph.count_paths(you, out), Some(2));
+        assert_eq!(graph.count_paths_to(out), None);
+        assert_eq!(graph.count_paths(graph.id("xxx").unwrap(), out), None);
+    }
+}
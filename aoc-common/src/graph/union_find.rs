@@ -0,0 +1,161 @@
+use super::NodeId;
+
+/// Disjoint sets over the ids `0..len`, every id starts in a set of its own.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<NodeId>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// the representative of the set, the path to it is halved along the way
+    pub fn find(&mut self, mut id: NodeId) -> NodeId {
+        while self.parents[id] != id {
+            self.parents[id] = self.parents[self.parents[id]];
+            id = self.parents[id];
+        }
+        id
+    }
+
+    /// Joins the sets of `a` and `b`, the smaller set goes below the larger one.
+    /// False when they were already in the same set.
+    pub fn union(&mut self, a: NodeId, b: NodeId) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: NodeId, b: NodeId) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// the size of the set that contains `id`
+    pub fn size(&mut self, id: NodeId) -> usize {
+        let root = self.find(id);
+        self.sizes[root]
+    }
+
+    /// the number of disjoint sets
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// the size of every set, in no particular order
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|id| self.parents[*id] == *id)
+            .map(|id| self.sizes[id])
+            .collect()
+    }
+}
+
+/// Kruskal's minimum spanning tree as an iterator over the edges that join two
+/// components, cheapest first. It stops once everything is connected, so the
+/// last edge is the one that unifies all nodes.
+pub struct Kruskal<W> {
+    edges: std::vec::IntoIter<(NodeId, NodeId, W)>,
+    sets: UnionFind,
+}
+
+impl<W> Kruskal<W> {
+    /// the components joined so far
+    pub fn sets(&self) -> &UnionFind {
+        &self.sets
+    }
+}
+
+impl<W> Iterator for Kruskal<W> {
+    type Item = (NodeId, NodeId, W);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.sets.components() <= 1 {
+            return None;
+        }
+        self.edges.find(|(a, b, _)| self.sets.union(*a, *b))
+    }
+}
+
+/// Edges with the same weight keep their order.
+pub fn kruskal<W: Ord>(
+    len: usize,
+    edges: impl IntoIterator<Item = (NodeId, NodeId, W)>,
+) -> Kruskal<W> {
+    let mut edges: Vec<_> = edges.into_iter().collect();
+    edges.sort_by(|a, b| a.2.cmp(&b.2));
+    Kruskal {
+        edges: edges.into_iter(),
+        sets: UnionFind::new(len),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_and_find() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.size(5), 1);
+        assert_eq!(sets.components(), 3);
+        let mut sizes = sets.component_sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 1, 4]);
+    }
+
+    #[test]
+    fn spanning_tree() {
+        let edges = [
+            (0, 1, 7),
+            (0, 3, 5),
+            (1, 2, 8),
+            (1, 3, 9),
+            (1, 4, 7),
+            (2, 4, 5),
+            (3, 4, 15),
+            (3, 5, 6),
+            (4, 5, 8),
+            (4, 6, 9),
+            (5, 6, 11),
+        ];
+        let tree: Vec<_> = kruskal(7, edges).collect();
+        assert_eq!(tree.len(), 6);
+        assert_eq!(tree.iter().map(|(_, _, w)| w).sum::<i32>(), 39);
+        assert_eq!(tree.last(), Some(&(4, 6, 9)));
+
+        let mut joined = kruskal(4, [(0, 1, 1), (2, 3, 1)]);
+        assert_eq!(joined.by_ref().count(), 2);
+        assert_eq!(joined.sets().components(), 2);
+    }
+}
@@ -0,0 +1,88 @@
+use super::position::Position;
+
+/// Inclusive bounding box. Like the older grids it starts at (0,0) and only grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Boundaries {
+    pub min_x: isize,
+    pub max_x: isize,
+    pub min_y: isize,
+    pub max_y: isize,
+}
+
+impl Boundaries {
+    pub fn new() -> Self {
+        Boundaries::default()
+    }
+
+    pub fn from_position(position: Position) -> Self {
+        Boundaries {
+            min_x: position.x,
+            max_x: position.x,
+            min_y: position.y,
+            max_y: position.y,
+        }
+    }
+
+    pub fn update(&mut self, x: isize, y: isize) {
+        if x > self.max_x {
+            self.max_x = x;
+        }
+        if x < self.min_x {
+            self.min_x = x;
+        }
+        if y > self.max_y {
+            self.max_y = y;
+        }
+        if y < self.min_y {
+            self.min_y = y;
+        }
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x >= self.min_x
+            && position.x <= self.max_x
+            && position.y >= self.min_y
+            && position.y <= self.max_y
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y + 1) as usize
+    }
+
+    pub fn top_left(&self) -> Position {
+        Position::new(self.min_x, self.min_y)
+    }
+
+    pub fn bottom_right(&self) -> Position {
+        Position::new(self.max_x, self.max_y)
+    }
+
+    /// row by row, left to right
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<> {
+        let Boundaries {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        } = *self;
+        (min_y..=max_y).flat_map(move |y| (min_x..=max_x).map(move |x| Position::new(x, y)))
+    }
+}
+
+#[test]
+fn update_and_iterate() {
+    let mut boundaries = Boundaries::new();
+    boundaries.update(2, -1);
+    assert_eq!(boundaries.width(), 3);
+    assert_eq!(boundaries.height(), 2);
+    assert!(boundaries.contains(Position::new(1, -1)));
+    assert!(!boundaries.contains(Position::new(3, 0)));
+    let positions: Vec<Position> = boundaries.positions().collect();
+    assert_eq!(positions.len(), 6);
+    assert_eq!(positions[0], Position::new(0, -1));
+    assert_eq!(positions[5], Position::new(2, 0));
+}
@@ -0,0 +1,283 @@
+use std::ops::{Index, IndexMut};
+
+use super::{Grid, boundaries::Boundaries, position::Position};
+
+/// Row-major grid in a single `Vec`, every cell within the boundaries has a value.
+/// The top left cell is the origin, (0,0) unless set with `with_origin`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseGrid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    origin: Position,
+}
+
+impl<T> DenseGrid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        DenseGrid {
+            cells: vec![value; width * height],
+            width,
+            height,
+            origin: Position::default(),
+        }
+    }
+
+    /// the cells of the rows one after the other
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = match width {
+            0 => 0,
+            _ => cells.len() / width,
+        };
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells do not fill rows of {}",
+            cells.len(),
+            width
+        );
+        DenseGrid {
+            cells,
+            width,
+            height,
+            origin: Position::default(),
+        }
+    }
+
+    pub fn from_fn(boundaries: Boundaries, mut value: impl FnMut(Position) -> T) -> Self {
+        DenseGrid {
+            cells: boundaries.positions().map(&mut value).collect(),
+            width: boundaries.width(),
+            height: boundaries.height(),
+            origin: boundaries.top_left(),
+        }
+    }
+
+    pub fn with_origin(mut self, origin: Position) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// index in the row-major cells, None outside the grid
+    pub fn index_of(&self, position: Position) -> Option<usize> {
+        let x = usize::try_from(position.x - self.origin.x).ok()?;
+        let y = usize::try_from(position.y - self.origin.y).ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn position_of(&self, index: usize) -> Position {
+        Position::new(
+            self.origin.x + (index % self.width) as isize,
+            self.origin.y + (index / self.width) as isize,
+        )
+    }
+
+    /// # Safety
+    ///
+    /// The position has to be within the grid, see `index_of`.
+    pub unsafe fn get_unchecked(&self, position: Position) -> &T {
+        let index = (position.y - self.origin.y) as usize * self.width
+            + (position.x - self.origin.x) as usize;
+        // SAFETY: the caller guarantees the position is inside the grid
+        unsafe { self.cells.get_unchecked(index) }
+    }
+
+    /// # Safety
+    ///
+    /// The position has to be within the grid, see `index_of`.
+    pub unsafe fn get_unchecked_mut(&mut self, position: Position) -> &mut T {
+        let index = (position.y - self.origin.y) as usize * self.width
+            + (position.x - self.origin.x) as usize;
+        // SAFETY: the caller guarantees the position is inside the grid
+        unsafe { self.cells.get_unchecked_mut(index) }
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn row(&self, y: isize) -> Option<&[T]> {
+        let start = self.index_of(Position::new(self.origin.x, y))?;
+        Some(&self.cells[start..start + self.width])
+    }
+
+    pub fn row_mut(&mut self, y: isize) -> Option<&mut [T]> {
+        let start = self.index_of(Position::new(self.origin.x, y))?;
+        Some(&mut self.cells[start..start + self.width])
+    }
+
+    /// the cells of a column from top to bottom
+    pub fn column(&self, x: isize) -> Option<impl DoubleEndedIterator<Item = &T>> {
+        let start = self.index_of(Position::new(x, self.origin.y))?;
+        Some(self.cells[start..].iter().step_by(self.width))
+    }
+
+    pub fn column_mut(&mut self, x: isize) -> Option<impl DoubleEndedIterator<Item = &mut T>> {
+        let start = self.index_of(Position::new(x, self.origin.y))?;
+        Some(self.cells[start..].iter_mut().step_by(self.width))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> DenseGrid<U> {
+        DenseGrid {
+            cells: self.cells.iter().map(&mut f).collect(),
+            width: self.width,
+            height: self.height,
+            origin: self.origin,
+        }
+    }
+
+    /// every cell with its position, row by row
+    pub fn into_cells(self) -> impl Iterator<Item = (Position, T)> {
+        let (origin, width) = (self.origin, self.width);
+        self.cells
+            .into_iter()
+            .enumerate()
+            .map(move |(index, value)| {
+                let x = origin.x + (index % width) as isize;
+                let y = origin.y + (index / width) as isize;
+                (Position::new(x, y), value)
+            })
+    }
+}
+
+impl<T> Grid<T> for DenseGrid<T> {
+    fn boundaries(&self) -> Boundaries {
+        Boundaries {
+            min_x: self.origin.x,
+            max_x: self.origin.x + self.width as isize - 1,
+            min_y: self.origin.y,
+            max_y: self.origin.y + self.height as isize - 1,
+        }
+    }
+
+    fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// the grid does not grow, setting a cell outside of it panics
+    fn set(&mut self, position: Position, value: T) {
+        self[position] = value;
+    }
+}
+
+impl<T> Index<Position> for DenseGrid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        match self.index_of(position) {
+            Some(index) => &self.cells[index],
+            None => panic!("{} is outside the grid", position),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for DenseGrid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        match self.index_of(position) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("{} is outside the grid", position),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::directions::BASIC_DIRECTIONS;
+
+    fn example() -> DenseGrid<u8> {
+        DenseGrid::from_vec(3, vec![1, 2, 3, 4, 5, 6])
+    }
+
+    #[test]
+    fn index_and_positions() {
+        let mut grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(2, 1)], 6);
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.get(Position::new(-1, 0)), None);
+        grid.set(Position::new(0, 1), 9);
+        assert_eq!(grid.index_of(Position::new(0, 1)), Some(3));
+        assert_eq!(grid.position_of(3), Position::new(0, 1));
+        assert_eq!(unsafe { *grid.get_unchecked(Position::new(0, 1)) }, 9);
+        assert_eq!(
+            grid.neighbors(Position::new(1, 0), &BASIC_DIRECTIONS)
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "(3,0) is outside the grid")]
+    fn set_outside() {
+        example().set(Position::new(3, 0), 1);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let mut grid = example();
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(
+            grid.column(1).unwrap().copied().collect::<Vec<_>>(),
+            vec![2, 5]
+        );
+        assert_eq!(
+            grid.column(2).unwrap().rev().copied().collect::<Vec<_>>(),
+            vec![6, 3]
+        );
+        for value in grid.column_mut(0).unwrap() {
+            *value = 0;
+        }
+        grid.row_mut(0).unwrap()[2] = 0;
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[0, 2, 0][..], &[0, 5, 6][..]]
+        );
+    }
+
+    #[test]
+    fn with_origin() {
+        let grid = example().with_origin(Position::new(-1, -1));
+        assert_eq!(grid[Position::new(-1, -1)], 1);
+        assert_eq!(grid.boundaries().bottom_right(), Position::new(1, 0));
+        let from_fn = DenseGrid::from_fn(grid.boundaries(), |position| grid[position] * 2);
+        assert_eq!(from_fn, grid.map(|value| value * 2));
+        let cells: Vec<(Position, u8)> = grid.into_cells().collect();
+        assert_eq!(cells[4], (Position::new(0, 0), 5));
+    }
+}
@@ -0,0 +1,51 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Direction {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Direction {
+    pub fn turn_right(&self) -> Direction {
+        Direction {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        Direction {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+
+    pub fn reverse(&self) -> Direction {
+        Direction {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+pub const UP: Direction = Direction { x: 0, y: -1 };
+pub const DOWN: Direction = Direction { x: 0, y: 1 };
+pub const LEFT: Direction = Direction { x: -1, y: 0 };
+pub const RIGHT: Direction = Direction { x: 1, y: 0 };
+pub const BASIC_DIRECTIONS: [Direction; 4] = [UP, DOWN, LEFT, RIGHT];
+
+pub const DOWN_LEFT: Direction = Direction { x: -1, y: 1 };
+pub const DOWN_RIGHT: Direction = Direction { x: 1, y: 1 };
+pub const UP_RIGHT: Direction = Direction { x: 1, y: -1 };
+pub const UP_LEFT: Direction = Direction { x: -1, y: -1 };
+/// clockwise, starting at the top
+pub const ALL_DIRECTIONS: [Direction; 8] = [
+    UP, UP_RIGHT, RIGHT, DOWN_RIGHT, DOWN, DOWN_LEFT, LEFT, UP_LEFT,
+];
+
+#[test]
+fn turning() {
+    assert_eq!(UP.turn_right(), RIGHT);
+    assert_eq!(RIGHT.turn_right(), DOWN);
+    assert_eq!(UP.turn_left(), LEFT);
+    assert_eq!(LEFT.reverse(), RIGHT);
+}
@@ -0,0 +1,474 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use super::{Grid, boundaries::Boundaries};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GRAY: Color = Color::rgb(128, 128, 128);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(80, 160, 60);
+    pub const BLUE: Color = Color::rgb(38, 110, 210);
+    pub const YELLOW: Color = Color::rgb(230, 190, 40);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b }
+    }
+
+    pub const fn gray(level: u8) -> Self {
+        Color::rgb(level, level, level)
+    }
+}
+
+#[derive(Debug)]
+pub enum ImageError {
+    Write {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// only .ppm and .png can be saved, frames also as .gif
+    UnsupportedFormat(PathBuf),
+    Encode(String),
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Write { path, source } => write!(f, "{}: {}", path.display(), source),
+            ImageError::UnsupportedFormat(path) => {
+                write!(f, "{}: unsupported image format", path.display())
+            }
+            ImageError::Encode(message) => write!(f, "encoding failed, {}", message),
+        }
+    }
+}
+
+impl Error for ImageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ImageError::Write { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> Result<(), ImageError> {
+    fs::write(path, bytes).map_err(|source| ImageError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// An rgb image, row by row from the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Every cell within the boundaries becomes a `scale` by `scale` square,
+    /// `color` gets None for the empty cells like `Grid::to_text`.
+    pub fn from_grid<T, G: Grid<T> + ?Sized>(
+        grid: &G,
+        boundaries: Boundaries,
+        scale: usize,
+        color: impl Fn(Option<&T>) -> Color,
+    ) -> Self {
+        let mut image = Image::new(
+            boundaries.width() * scale,
+            boundaries.height() * scale,
+            Color::BLACK,
+        );
+        for position in boundaries.positions() {
+            let cell = color(grid.get(position));
+            let x = (position.x - boundaries.min_x) as usize * scale;
+            let y = (position.y - boundaries.min_y) as usize * scale;
+            for dy in 0..scale {
+                let start = (y + dy) * image.width + x;
+                image.pixels[start..start + scale].fill(cell);
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[Color] {
+        &self.pixels
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Color> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// panics outside the image
+    pub fn set(&mut self, x: usize, y: usize, color: Color) {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the image",
+            x,
+            y
+        );
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// a copy with the given size, cut off or filled up with `background`
+    pub fn resized(&self, width: usize, height: usize, background: Color) -> Image {
+        let mut image = Image::new(width, height, background);
+        for y in 0..height.min(self.height) {
+            for x in 0..width.min(self.width) {
+                image.pixels[y * width + x] = self.pixels[y * self.width + x];
+            }
+        }
+        image
+    }
+
+    fn rgb_bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|color| [color.r, color.g, color.b])
+            .collect()
+    }
+
+    /// binary ppm, readable by about every image viewer without any encoding
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.rgb_bytes());
+        bytes
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, ImageError> {
+        let encode = |error: png::EncodingError| ImageError::Encode(error.to_string());
+        let mut bytes = vec![];
+        let mut encoder = png::Encoder::new(&mut bytes, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(encode)?;
+        writer.write_image_data(&self.rgb_bytes()).map_err(encode)?;
+        writer.finish().map_err(encode)?;
+        Ok(bytes)
+    }
+
+    /// the format follows the extension, .ppm or .png
+    pub fn save(&self, path: &Path) -> Result<(), ImageError> {
+        let bytes = match extension(path).as_deref() {
+            Some("ppm") => self.to_ppm(),
+            Some("png") => self.to_png()?,
+            _ => return Err(ImageError::UnsupportedFormat(path.to_path_buf())),
+        };
+        write_file(path, &bytes)
+    }
+}
+
+fn extension(path: &Path) -> Option<String> {
+    Some(path.extension()?.to_str()?.to_ascii_lowercase())
+}
+
+/// Animated gif of the frames, smaller frames are filled up with black to
+/// the size of the largest one.
+pub fn encode_gif(frames: &[Image], delay: Duration) -> Result<Vec<u8>, ImageError> {
+    let encode = |error: gif::EncodingError| ImageError::Encode(error.to_string());
+    let width = frames.iter().map(Image::width).max().unwrap_or(0);
+    let height = frames.iter().map(Image::height).max().unwrap_or(0);
+    let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(ImageError::Encode(format!(
+            "{}x{} is too large for a gif",
+            width, height
+        )));
+    };
+
+    // puzzle grids rarely have more colors than fit in a single palette
+    let mut indices: HashMap<Color, u8> = HashMap::from([(Color::BLACK, 0)]);
+    let mut palette = vec![Color::BLACK];
+    for color in frames.iter().flat_map(|frame| frame.pixels()) {
+        if palette.len() > 256 {
+            break;
+        }
+        if !indices.contains_key(color) {
+            indices.insert(*color, palette.len() as u8);
+            palette.push(*color);
+        }
+    }
+    let global_palette: Vec<u8> = match palette.len() <= 256 {
+        true => palette.iter().flat_map(|c| [c.r, c.g, c.b]).collect(),
+        false => vec![],
+    };
+
+    let mut bytes = vec![];
+    {
+        let mut encoder = gif::Encoder::new(&mut bytes, gif_width, gif_height, &global_palette)
+            .map_err(encode)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(encode)?;
+        for frame in frames {
+            let frame = frame.resized(width, height, Color::BLACK);
+            let mut gif_frame = match global_palette.is_empty() {
+                false => {
+                    let pixels: Vec<u8> = frame.pixels().iter().map(|c| indices[c]).collect();
+                    gif::Frame::from_indexed_pixels(gif_width, gif_height, pixels, None)
+                }
+                true => gif::Frame::from_rgb_speed(gif_width, gif_height, &frame.rgb_bytes(), 10),
+            };
+            gif_frame.delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+            encoder.write_frame(&gif_frame).map_err(encode)?;
+        }
+    }
+    Ok(bytes)
+}
+
+enum Output {
+    Numbered {
+        directory: PathBuf,
+        prefix: String,
+        extension: String,
+    },
+    Gif {
+        path: PathBuf,
+        delay: Duration,
+        frames: Vec<Image>,
+    },
+}
+
+/// Captures the state of a simulation step by step, either as numbered image
+/// files or as a single animated gif that is written by `finish`.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use aoc_common::grid::{Color, FrameRecorder, Grid, Position, SparseGrid};
+///
+/// let mut recorder = FrameRecorder::gif("sand.gif", Duration::from_millis(40)).with_every(10);
+/// let mut grid = SparseGrid::new();
+/// for x in 0..100 {
+///     grid.set(Position::new(x, 0), true);
+///     recorder.record_grid(&grid, 4, |cell| match cell {
+///         Some(_) => Color::YELLOW,
+///         None => Color::BLACK,
+///     }).unwrap();
+/// }
+/// recorder.finish().unwrap();
+/// ```
+pub struct FrameRecorder {
+    output: Output,
+    every: usize,
+    steps: usize,
+    recorded: usize,
+}
+
+impl FrameRecorder {
+    /// frames named `frame_00000.png` and up in the directory, created when missing
+    pub fn numbered(directory: impl Into<PathBuf>) -> Self {
+        FrameRecorder::with_output(Output::Numbered {
+            directory: directory.into(),
+            prefix: "frame".to_string(),
+            extension: "png".to_string(),
+        })
+    }
+
+    pub fn gif(path: impl Into<PathBuf>, delay: Duration) -> Self {
+        FrameRecorder::with_output(Output::Gif {
+            path: path.into(),
+            delay,
+            frames: vec![],
+        })
+    }
+
+    fn with_output(output: Output) -> Self {
+        FrameRecorder {
+            output,
+            every: 1,
+            steps: 0,
+            recorded: 0,
+        }
+    }
+
+    /// the file name prefix and format of numbered frames, ignored for a gif
+    pub fn with_format(mut self, new_prefix: &str, new_extension: &str) -> Self {
+        if let Output::Numbered {
+            prefix, extension, ..
+        } = &mut self.output
+        {
+            *prefix = new_prefix.to_string();
+            *extension = new_extension.to_string();
+        }
+        self
+    }
+
+    /// only keeps every nth step, long simulations have too many of them
+    pub fn with_every(mut self, every: usize) -> Self {
+        self.every = every.max(1);
+        self
+    }
+
+    /// the number of frames kept so far
+    pub fn frames(&self) -> usize {
+        self.recorded
+    }
+
+    /// counts the step, true when it is one to keep
+    fn next_step(&mut self) -> bool {
+        self.steps += 1;
+        (self.steps - 1).is_multiple_of(self.every)
+    }
+
+    pub fn record(&mut self, image: Image) -> Result<(), ImageError> {
+        if !self.next_step() {
+            return Ok(());
+        }
+        self.keep(image)
+    }
+
+    /// records the grid within its current boundaries, skipped steps are not rendered
+    pub fn record_grid<T, G: Grid<T> + ?Sized>(
+        &mut self,
+        grid: &G,
+        scale: usize,
+        color: impl Fn(Option<&T>) -> Color,
+    ) -> Result<(), ImageError> {
+        if !self.next_step() {
+            return Ok(());
+        }
+        self.keep(grid.to_image(scale, color))
+    }
+
+    fn keep(&mut self, image: Image) -> Result<(), ImageError> {
+        match &mut self.output {
+            Output::Numbered {
+                directory,
+                prefix,
+                extension,
+            } => {
+                fs::create_dir_all(&*directory).map_err(|source| ImageError::Write {
+                    path: directory.clone(),
+                    source,
+                })?;
+                let path = directory.join(format!("{}_{:05}.{}", prefix, self.recorded, extension));
+                image.save(&path)?;
+            }
+            Output::Gif { frames, .. } => frames.push(image),
+        }
+        self.recorded += 1;
+        Ok(())
+    }
+
+    /// Writes the gif, numbered frames are already written. Returns the number of frames.
+    pub fn finish(self) -> Result<usize, ImageError> {
+        if let Output::Gif {
+            path,
+            delay,
+            frames,
+        } = &self.output
+        {
+            if extension(path).as_deref() != Some("gif") {
+                return Err(ImageError::UnsupportedFormat(path.clone()));
+            }
+            write_file(path, &encode_gif(frames, *delay)?)?;
+        }
+        Ok(self.recorded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{DenseGrid, Position, SparseGrid};
+
+    fn checkers() -> DenseGrid<bool> {
+        DenseGrid::from_vec(3, vec![true, false, true, false, true, false])
+    }
+
+    fn color(cell: Option<&bool>) -> Color {
+        match cell {
+            Some(true) => Color::WHITE,
+            Some(false) => Color::RED,
+            None => Color::BLACK,
+        }
+    }
+
+    #[test]
+    fn render_grid() {
+        let image = checkers().to_image(2, color);
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.get(1, 1), Some(Color::WHITE));
+        assert_eq!(image.get(2, 1), Some(Color::RED));
+        assert_eq!(image.get(3, 3), Some(Color::WHITE));
+        assert_eq!(image.get(6, 0), None);
+
+        let mut sparse = SparseGrid::new();
+        sparse.set(Position::new(-1, -1), true);
+        sparse.set(Position::new(1, 0), false);
+        let image = sparse.to_image(1, color);
+        assert_eq!((image.width(), image.height()), (3, 2));
+        assert_eq!(
+            image.pixels()[..3],
+            [Color::WHITE, Color::BLACK, Color::BLACK]
+        );
+        assert_eq!(image.get(2, 1), Some(Color::RED));
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 3 * 2 * 3);
+        assert!(image.to_png().unwrap().starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn record_frames() {
+        let directory = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut grid = checkers();
+        let mut frames = FrameRecorder::numbered(&directory).with_every(2);
+        let mut gif = FrameRecorder::gif(directory.join("steps.gif"), Duration::from_millis(50));
+        for x in 0..3 {
+            grid.set(Position::new(x, 1), true);
+            frames.record_grid(&grid, 1, color).unwrap();
+            gif.record(
+                grid.to_image(1, color)
+                    .resized(x as usize + 1, 2, Color::BLACK),
+            )
+            .unwrap();
+        }
+        assert_eq!(frames.finish().unwrap(), 2);
+        assert!(directory.join("frame_00001.png").exists());
+        assert!(!directory.join("frame_00002.png").exists());
+        assert_eq!(gif.finish().unwrap(), 3);
+        let bytes = fs::read(directory.join("steps.gif")).unwrap();
+        assert!(bytes.starts_with(b"GIF89a"));
+        // the largest frame sets the size
+        assert_eq!(&bytes[6..10], &[3, 0, 2, 0]);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn unsupported_format() {
+        let image = Image::new(1, 1, Color::BLACK);
+        let error = image.save(Path::new("frame.bmp")).err().unwrap();
+        assert_eq!(error.to_string(), "frame.bmp: unsupported image format");
+    }
+}
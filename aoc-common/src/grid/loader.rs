@@ -0,0 +1,268 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::{
+    Grid, boundaries::Boundaries, dense_grid::DenseGrid, position::Position,
+    sparse_grid::SparseGrid,
+};
+
+/// Cell values that can be read from a single character of the puzzle input.
+pub trait FromChar: Sized {
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl FromChar for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+/// digit maps like heights or risk levels
+impl FromChar for u8 {
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|digit| digit as u8)
+    }
+}
+
+/// Grids that can be built from the loaded cells, which come row by row.
+/// None when the cells do not fit the grid, like gaps in a dense grid.
+pub trait FromCells<T>: Sized {
+    fn from_cells(boundaries: Boundaries, cells: Vec<(Position, T)>) -> Option<Self>;
+}
+
+impl<T> FromCells<T> for SparseGrid<T> {
+    fn from_cells(boundaries: Boundaries, cells: Vec<(Position, T)>) -> Option<Self> {
+        let mut grid = SparseGrid::with_boundaries(boundaries);
+        for (position, value) in cells {
+            grid.set(position, value);
+        }
+        Some(grid)
+    }
+}
+
+impl<T> FromCells<T> for DenseGrid<T> {
+    fn from_cells(boundaries: Boundaries, cells: Vec<(Position, T)>) -> Option<Self> {
+        let positions = boundaries.positions();
+        if cells.len() != boundaries.width() * boundaries.height()
+            || !positions.zip(cells.iter()).all(|(a, (b, _))| a == *b)
+        {
+            return None;
+        }
+        let values = cells.into_iter().map(|(_, value)| value).collect();
+        Some(DenseGrid::from_vec(boundaries.width(), values).with_origin(boundaries.top_left()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadError {
+    /// line and column start at 1, like in an editor
+    InvalidChar {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    /// the lines have different lengths or characters were skipped
+    NotRectangular,
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::InvalidChar {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "invalid character {:?} at line {}, column {}",
+                found, line, column
+            ),
+            LoadError::NotRectangular => write!(f, "the input does not fill the grid"),
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+/// The loaded grid and where the marker characters were found.
+pub struct Loaded<G> {
+    pub grid: G,
+    markers: HashMap<char, Vec<Position>>,
+}
+
+impl<G> Loaded<G> {
+    /// the first position of the marker
+    pub fn marker(&self, c: char) -> Option<Position> {
+        self.markers(c).first().copied()
+    }
+
+    /// every position of the marker in reading order
+    pub fn markers(&self, c: char) -> &[Position] {
+        self.markers
+            .get(&c)
+            .map_or(&[], |positions| positions.as_slice())
+    }
+}
+
+/// Builds grids from puzzle text, the first character of the first line is the origin.
+///
+/// ```
+/// use aoc_common::grid::{GridLoader, Position, SparseGrid};
+///
+/// let loaded = GridLoader::new()
+///     .with_markers(&['S'])
+///     .with_skipped(&['.'])
+///     .load_chars::<SparseGrid<char>, char>("S.#\n..#")
+///     .unwrap();
+/// assert_eq!(loaded.marker('S'), Some(Position::new(0, 0)));
+/// assert_eq!(loaded.grid.len(), 3);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GridLoader {
+    origin: Position,
+    markers: Vec<char>,
+    skipped: Vec<char>,
+}
+
+impl GridLoader {
+    pub fn new() -> Self {
+        GridLoader::default()
+    }
+
+    pub fn with_origin(mut self, origin: Position) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// markers are still parsed as cells, their positions are kept as well
+    pub fn with_markers(mut self, markers: &[char]) -> Self {
+        self.markers.extend_from_slice(markers);
+        self
+    }
+
+    /// skipped characters leave the cell empty, only sparse grids allow that
+    pub fn with_skipped(mut self, skipped: &[char]) -> Self {
+        self.skipped.extend_from_slice(skipped);
+        self
+    }
+
+    /// `parse` returns None for characters that are not valid in the grid
+    pub fn load<G: FromCells<T>, T>(
+        &self,
+        text: &str,
+        mut parse: impl FnMut(char) -> Option<T>,
+    ) -> Result<Loaded<G>, LoadError> {
+        let mut cells = vec![];
+        let mut markers: HashMap<char, Vec<Position>> = HashMap::new();
+        let mut boundaries = Boundaries::from_position(self.origin);
+        let mut height = 0;
+        for (y, line) in text.lines().enumerate() {
+            height = y + 1;
+            for (x, c) in line.chars().enumerate() {
+                let position =
+                    Position::new(self.origin.x + x as isize, self.origin.y + y as isize);
+                boundaries.update(position.x, position.y);
+                if self.markers.contains(&c) {
+                    markers.entry(c).or_default().push(position);
+                }
+                if self.skipped.contains(&c) {
+                    continue;
+                }
+                let value = parse(c).ok_or(LoadError::InvalidChar {
+                    line: y + 1,
+                    column: x + 1,
+                    found: c,
+                })?;
+                cells.push((position, value));
+            }
+        }
+        if height == 0 {
+            // an empty text has no cells, not even the origin
+            boundaries.max_x = self.origin.x - 1;
+            boundaries.max_y = self.origin.y - 1;
+        }
+        let grid = G::from_cells(boundaries, cells).ok_or(LoadError::NotRectangular)?;
+        Ok(Loaded { grid, markers })
+    }
+
+    pub fn load_chars<G: FromCells<T>, T: FromChar>(
+        &self,
+        text: &str,
+    ) -> Result<Loaded<G>, LoadError> {
+        self.load(text, T::from_char)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "Sab\nbcE\n";
+
+    #[test]
+    fn load_with_markers() {
+        let loaded: Loaded<DenseGrid<char>> = GridLoader::new()
+            .with_markers(&['S', 'E'])
+            .load_chars(MAP)
+            .unwrap();
+        assert_eq!(loaded.marker('S'), Some(Position::new(0, 0)));
+        assert_eq!(loaded.marker('E'), Some(Position::new(2, 1)));
+        assert_eq!(loaded.marker('x'), None);
+        assert_eq!(loaded.grid.row(1), Some(&['b', 'c', 'E'][..]));
+
+        let loaded: Loaded<SparseGrid<char>> = GridLoader::new()
+            .with_origin(Position::new(-1, 5))
+            .with_markers(&['b'])
+            .with_skipped(&['b'])
+            .load_chars(MAP)
+            .unwrap();
+        assert_eq!(
+            loaded.markers('b'),
+            &[Position::new(1, 5), Position::new(-1, 6)]
+        );
+        assert_eq!(loaded.grid.len(), 4);
+        assert_eq!(loaded.grid.boundaries().bottom_right(), Position::new(1, 6));
+    }
+
+    #[test]
+    fn invalid_characters() {
+        let error = GridLoader::new()
+            .load_chars::<DenseGrid<u8>, u8>("123\n4x6")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error,
+            LoadError::InvalidChar {
+                line: 2,
+                column: 2,
+                found: 'x'
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "invalid character 'x' at line 2, column 2"
+        );
+
+        let ragged = GridLoader::new().load_chars::<DenseGrid<u8>, u8>("123\n45\n6789");
+        assert_eq!(ragged.err(), Some(LoadError::NotRectangular));
+        let ragged = GridLoader::new().load_chars::<SparseGrid<u8>, u8>("123\n45");
+        assert_eq!(ragged.unwrap().grid.len(), 5);
+    }
+
+    #[test]
+    fn load_with_closure() {
+        let loaded: Loaded<DenseGrid<bool>> = GridLoader::new()
+            .load("#.\n.#", |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(loaded.grid.cells(), &[true, false, false, true]);
+        let empty: Loaded<DenseGrid<bool>> = GridLoader::new().load("", |_| None).unwrap();
+        assert!(empty.grid.is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+pub mod boundaries;
+pub mod dense_grid;
+pub mod directions;
+pub mod image;
+pub mod loader;
+pub mod position;
+pub mod region;
+pub mod search;
+pub mod sparse_grid;
+pub mod symmetry;
+pub mod terminal;
+
+pub use boundaries::Boundaries;
+pub use dense_grid::DenseGrid;
+pub use directions::Direction;
+pub use image::{Color, FrameRecorder, Image, ImageError};
+pub use loader::{FromCells, FromChar, GridLoader, LoadError, Loaded};
+pub use position::Position;
+pub use region::{Region, Regions, flood_fill};
+pub use search::{Search, a_star, bfs, dijkstra};
+pub use sparse_grid::SparseGrid;
+pub use symmetry::Transform;
+pub use terminal::{LiveView, Style, TerminalRenderer};
+
+/// The shared 2d grid api. Storage types only implement the four required
+/// methods, everything else is derived from them.
+pub trait Grid<T> {
+    fn boundaries(&self) -> Boundaries;
+    fn get(&self, position: Position) -> Option<&T>;
+    fn get_mut(&mut self, position: Position) -> Option<&mut T>;
+    fn set(&mut self, position: Position, value: T);
+
+    fn contains(&self, position: Position) -> bool {
+        self.get(position).is_some()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        position: Position,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Position, &'a T)>
+    where
+        T: 'a,
+    {
+        directions.iter().filter_map(move |direction| {
+            let neighbor = position.step(direction);
+            self.get(neighbor).map(|value| (neighbor, value))
+        })
+    }
+
+    /// every filled cell within the boundaries, row by row
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Position, &'a T)>
+    where
+        T: 'a,
+    {
+        self.boundaries()
+            .positions()
+            .filter_map(move |position| self.get(position).map(|value| (position, value)))
+    }
+
+    fn to_text(&self, print_value: impl Fn(Option<&T>) -> String) -> String {
+        let boundaries = self.boundaries();
+        let mut output = String::new();
+        for y in boundaries.min_y..=boundaries.max_y {
+            for x in boundaries.min_x..=boundaries.max_x {
+                output += &print_value(self.get(Position::new(x, y)));
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    /// the grid within its boundaries as an image, see `Image::from_grid`
+    fn to_image(&self, scale: usize, color: impl Fn(Option<&T>) -> Color) -> Image {
+        Image::from_grid(self, self.boundaries(), scale, color)
+    }
+
+    fn print(&self, print_value: impl Fn(Option<&T>) -> String) {
+        let Boundaries {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        } = self.boundaries();
+        println!("x: {} - {} y: {} - {}", min_x, max_x, min_y, max_y);
+        print!("{}", self.to_text(print_value));
+    }
+}
@@ -0,0 +1,71 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Sub},
+};
+
+use super::directions::Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Position {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Position { x, y }
+    }
+
+    pub fn step(&self, direction: &Direction) -> Position {
+        Position {
+            x: self.x + direction.x,
+            y: self.y + direction.y,
+        }
+    }
+
+    pub fn manhattan_distance(&self, other: &Position) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add<Direction> for Position {
+    type Output = Position;
+
+    fn add(self, direction: Direction) -> Self::Output {
+        self.step(&direction)
+    }
+}
+
+impl Sub for Position {
+    type Output = Direction;
+
+    fn sub(self, other: Position) -> Self::Output {
+        Direction {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl From<(isize, isize)> for Position {
+    fn from((x, y): (isize, isize)) -> Self {
+        Position { x, y }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+#[test]
+fn step_and_distance() {
+    use super::directions::{DOWN_LEFT, UP};
+
+    let position = Position::new(3, 4);
+    assert_eq!(position + UP, Position::new(3, 3));
+    assert_eq!(position.step(&DOWN_LEFT), Position::new(2, 5));
+    assert_eq!(position - Position::new(1, 1), Direction { x: 2, y: 3 });
+    assert_eq!(position.manhattan_distance(&Position::new(-1, 6)), 6);
+}
@@ -0,0 +1,313 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::{
+    Grid,
+    boundaries::Boundaries,
+    directions::{BASIC_DIRECTIONS, DOWN, LEFT, RIGHT, UP},
+    position::Position,
+};
+
+/// A connected set of cells, connected through their four direct neighbors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// row by row
+    cells: Vec<Position>,
+    touches_border: bool,
+}
+
+impl Region {
+    fn new(mut cells: Vec<Position>, grid_boundaries: Boundaries) -> Self {
+        cells.sort_by_key(|cell| (cell.y, cell.x));
+        let touches_border = cells.iter().any(|cell| {
+            cell.x == grid_boundaries.min_x
+                || cell.x == grid_boundaries.max_x
+                || cell.y == grid_boundaries.min_y
+                || cell.y == grid_boundaries.max_y
+        });
+        Region {
+            cells,
+            touches_border,
+        }
+    }
+
+    /// row by row
+    pub fn cells(&self) -> &[Position] {
+        &self.cells
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        self.cells
+            .binary_search_by_key(&(position.y, position.x), |cell| (cell.y, cell.x))
+            .is_ok()
+    }
+
+    /// the cell edges that border a cell outside the region, holes included
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|cell| {
+                BASIC_DIRECTIONS
+                    .iter()
+                    .filter(|direction| !self.contains(cell.step(direction)))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The straight sides of the outline, holes included. A polygon has as
+    /// many sides as corners, so the corners of every cell are counted.
+    pub fn sides(&self) -> usize {
+        let corners = [(UP, RIGHT), (RIGHT, DOWN), (DOWN, LEFT), (LEFT, UP)];
+        self.cells
+            .iter()
+            .map(|cell| {
+                corners
+                    .iter()
+                    .filter(|(a, b)| {
+                        let first = self.contains(cell.step(a));
+                        let second = self.contains(cell.step(b));
+                        let diagonal = self.contains(cell.step(a).step(b));
+                        (!first && !second) || (first && second && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// the bounding box of the cells
+    pub fn boundaries(&self) -> Boundaries {
+        let mut boundaries = Boundaries::from_position(self.cells[0]);
+        for cell in self.cells.iter() {
+            boundaries.update(cell.x, cell.y);
+        }
+        boundaries
+    }
+
+    /// true when a cell lies on the edge of the grid the region was found in
+    pub fn touches_border(&self) -> bool {
+        self.touches_border
+    }
+
+    /// The cells outside the region that can not reach the outside without
+    /// crossing it, row by row.
+    pub fn enclosed(&self) -> Vec<Position> {
+        let inner = self.boundaries();
+        let outer = Boundaries {
+            min_x: inner.min_x - 1,
+            max_x: inner.max_x + 1,
+            min_y: inner.min_y - 1,
+            max_y: inner.max_y + 1,
+        };
+        let mut outside = HashSet::from([outer.top_left()]);
+        let mut queue = VecDeque::from([outer.top_left()]);
+        while let Some(position) = queue.pop_front() {
+            for direction in BASIC_DIRECTIONS.iter() {
+                let next = position.step(direction);
+                if outer.contains(next) && !self.contains(next) && outside.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        inner
+            .positions()
+            .filter(|position| !self.contains(*position) && !outside.contains(position))
+            .collect()
+    }
+
+    /// the number of separate groups of enclosed cells
+    pub fn holes(&self) -> usize {
+        let enclosed = self.enclosed();
+        let mut seen: HashSet<Position> = HashSet::new();
+        let mut holes = 0;
+        for start in enclosed.iter() {
+            if !seen.insert(*start) {
+                continue;
+            }
+            holes += 1;
+            let mut queue = VecDeque::from([*start]);
+            while let Some(position) = queue.pop_front() {
+                for direction in BASIC_DIRECTIONS.iter() {
+                    let next = position.step(direction);
+                    if enclosed
+                        .binary_search_by_key(&(next.y, next.x), |cell| (cell.y, cell.x))
+                        .is_ok()
+                        && seen.insert(next)
+                    {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        holes
+    }
+}
+
+/// The region around `start`, growing to every neighbor for which
+/// `same_region(from, to)` holds. None when the start is not in the grid.
+pub fn flood_fill<T, G: Grid<T> + ?Sized>(
+    grid: &G,
+    start: Position,
+    mut same_region: impl FnMut(&T, &T) -> bool,
+) -> Option<Region> {
+    grid.get(start)?;
+    let mut cells = vec![start];
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(position) = queue.pop_front() {
+        let value = grid.get(position).unwrap();
+        for (neighbor, neighbor_value) in grid.neighbors(position, &BASIC_DIRECTIONS) {
+            if !seen.contains(&neighbor) && same_region(value, neighbor_value) {
+                seen.insert(neighbor);
+                cells.push(neighbor);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    Some(Region::new(cells, grid.boundaries()))
+}
+
+/// Every filled cell of a grid labeled with the region it belongs to.
+///
+/// ```
+/// use aoc_common::grid::{DenseGrid, GridLoader, Regions};
+///
+/// let grid: DenseGrid<char> = GridLoader::new().load_chars("AAB\nABB\nCCB").unwrap().grid;
+/// let regions = Regions::new(&grid, |a, b| a == b);
+/// assert_eq!(regions.len(), 3);
+/// let b = regions.region_at((2, 0).into()).unwrap();
+/// assert_eq!((b.area(), b.perimeter(), b.sides()), (4, 10, 8));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Regions {
+    labels: HashMap<Position, usize>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// `same_region` is called for neighboring cells, an equivalence relation
+    /// like equal values gives regions that do not depend on the order
+    pub fn new<T, G: Grid<T> + ?Sized>(
+        grid: &G,
+        mut same_region: impl FnMut(&T, &T) -> bool,
+    ) -> Self {
+        let mut labels = HashMap::new();
+        let mut regions = vec![];
+        for (position, _) in grid.iter() {
+            if labels.contains_key(&position) {
+                continue;
+            }
+            let region = flood_fill(grid, position, |a, b| same_region(a, b)).unwrap();
+            for cell in region.cells() {
+                labels.insert(*cell, regions.len());
+            }
+            regions.push(region);
+        }
+        Regions { labels, regions }
+    }
+
+    /// the label of the region, labels follow the order the regions start in
+    pub fn label(&self, position: Position) -> Option<usize> {
+        self.labels.get(&position).copied()
+    }
+
+    pub fn region(&self, label: usize) -> &Region {
+        &self.regions[label]
+    }
+
+    pub fn region_at(&self, position: Position) -> Option<&Region> {
+        self.label(position).map(|label| &self.regions[label])
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{DenseGrid, GridLoader, SparseGrid};
+
+    fn load(text: &str) -> DenseGrid<char> {
+        GridLoader::new().load_chars(text).unwrap().grid
+    }
+
+    #[test]
+    fn garden_plots() {
+        // the larger example of 2024 day 12
+        let grid = load(
+            "RRRRIICCFF\nRRRRIICCCF\nVVRRRCCFFF\nVVRCCCJFFF\nVVVVCJJCFE\nVVIVCCJJEE\nVVIIICJJEE\nMIIIIIJJEE\nMIIISIJEEE\nMMMISSJEEE",
+        );
+        let regions = Regions::new(&grid, |a, b| a == b);
+        assert_eq!(regions.len(), 11);
+        let price: usize = regions
+            .iter()
+            .map(|region| region.area() * region.perimeter())
+            .sum();
+        assert_eq!(price, 1930);
+        let discount: usize = regions
+            .iter()
+            .map(|region| region.area() * region.sides())
+            .sum();
+        assert_eq!(discount, 1206);
+        assert_eq!(regions.label(Position::new(0, 0)), Some(0));
+        assert_eq!(regions.region_at(Position::new(10, 0)), None);
+    }
+
+    #[test]
+    fn holes_and_borders() {
+        let grid = load("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA");
+        let regions = Regions::new(&grid, |a, b| a == b);
+        let outer = regions.region_at(Position::new(0, 0)).unwrap();
+        assert_eq!(outer.area(), 28);
+        assert_eq!(outer.sides(), 12);
+        assert_eq!(outer.holes(), 2);
+        assert_eq!(outer.enclosed().len(), 8);
+        assert!(outer.touches_border());
+
+        let inner = regions.region_at(Position::new(3, 1)).unwrap();
+        assert_eq!(inner.sides(), 4);
+        assert_eq!(inner.holes(), 0);
+        assert!(!inner.touches_border());
+        assert_eq!(
+            inner.boundaries(),
+            Boundaries {
+                min_x: 3,
+                max_x: 4,
+                min_y: 1,
+                max_y: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn fill_sparse() {
+        let mut grid = SparseGrid::new();
+        for x in 0..3 {
+            grid.set(Position::new(x, 0), 1);
+        }
+        grid.set(Position::new(2, 1), 5);
+        grid.set(Position::new(5, 5), 1);
+        let region = flood_fill(&grid, Position::new(0, 0), |a: &i32, b: &i32| {
+            a.abs_diff(*b) <= 1
+        })
+        .unwrap();
+        assert_eq!(region.area(), 3);
+        let region = flood_fill(&grid, Position::new(0, 0), |_, _| true).unwrap();
+        assert_eq!(region.area(), 4);
+        assert!(region.contains(Position::new(2, 1)));
+        assert!(flood_fill(&grid, Position::new(1, 1), |_, _| true).is_none());
+    }
+}
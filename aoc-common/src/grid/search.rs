@@ -0,0 +1,243 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    ops::Add,
+};
+
+use super::{Grid, directions::Direction, position::Position};
+
+/// The outcome of a search: the distance of every reached position to the
+/// closest start and the position it was reached from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<C> {
+    distances: HashMap<Position, C>,
+    predecessors: HashMap<Position, Position>,
+}
+
+impl<C: Copy> Search<C> {
+    pub fn distance(&self, position: Position) -> Option<C> {
+        self.distances.get(&position).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<Position, C> {
+        &self.distances
+    }
+
+    pub fn is_reached(&self, position: Position) -> bool {
+        self.distances.contains_key(&position)
+    }
+
+    /// None for the starts and for positions that were not reached
+    pub fn predecessor(&self, position: Position) -> Option<Position> {
+        self.predecessors.get(&position).copied()
+    }
+
+    /// the positions from the start up to and including `to`
+    pub fn path(&self, to: Position) -> Option<Vec<Position>> {
+        if !self.is_reached(to) {
+            return None;
+        }
+        let mut path = vec![to];
+        while let Some(previous) = self.predecessor(*path.last().unwrap()) {
+            path.push(previous);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth first search from every start at once, `can_move(from, to)` decides
+/// whether a step between two neighboring cells is allowed.
+pub fn bfs<T, G: Grid<T>>(
+    grid: &G,
+    starts: impl IntoIterator<Item = Position>,
+    directions: &[Direction],
+    mut can_move: impl FnMut(&T, &T) -> bool,
+) -> Search<usize> {
+    let mut search = Search {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if grid.contains(start) && !search.is_reached(start) {
+            search.distances.insert(start, 0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(position) = queue.pop_front() {
+        let distance = search.distances[&position];
+        let value = grid.get(position).unwrap();
+        for (neighbor, neighbor_value) in grid.neighbors(position, directions) {
+            if search.is_reached(neighbor) || !can_move(value, neighbor_value) {
+                continue;
+            }
+            search.distances.insert(neighbor, distance + 1);
+            search.predecessors.insert(neighbor, position);
+            queue.push_back(neighbor);
+        }
+    }
+    search
+}
+
+/// Shared by dijkstra and A*, a zero heuristic without a goal explores everything.
+fn best_first<T, G, C>(
+    grid: &G,
+    starts: impl IntoIterator<Item = Position>,
+    directions: &[Direction],
+    mut cost: impl FnMut(&T, &T) -> Option<C>,
+    heuristic: impl Fn(Position) -> C,
+    goal: Option<Position>,
+) -> Search<C>
+where
+    G: Grid<T>,
+    C: Copy + Ord + Default + Add<Output = C>,
+{
+    let mut search = Search {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+    };
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if grid.contains(start) && !search.is_reached(start) {
+            search.distances.insert(start, C::default());
+            queue.push(Reverse((heuristic(start), C::default(), start)));
+        }
+    }
+    while let Some(Reverse((_, distance, position))) = queue.pop() {
+        if distance > search.distances[&position] {
+            continue;
+        }
+        if goal == Some(position) {
+            break;
+        }
+        let value = grid.get(position).unwrap();
+        for (neighbor, neighbor_value) in grid.neighbors(position, directions) {
+            let Some(step) = cost(value, neighbor_value) else {
+                continue;
+            };
+            let next = distance + step;
+            if search.distance(neighbor).is_some_and(|known| known <= next) {
+                continue;
+            }
+            search.distances.insert(neighbor, next);
+            search.predecessors.insert(neighbor, position);
+            queue.push(Reverse((next + heuristic(neighbor), next, neighbor)));
+        }
+    }
+    search
+}
+
+/// Cheapest distances from the starts, `cost(from, to)` returns None when the step is not allowed.
+pub fn dijkstra<T, G, C>(
+    grid: &G,
+    starts: impl IntoIterator<Item = Position>,
+    directions: &[Direction],
+    cost: impl FnMut(&T, &T) -> Option<C>,
+) -> Search<C>
+where
+    G: Grid<T>,
+    C: Copy + Ord + Default + Add<Output = C>,
+{
+    best_first(grid, starts, directions, cost, |_| C::default(), None)
+}
+
+/// Cheapest path to the goal with its cost. The heuristic estimates the remaining
+/// cost and must never overestimate it, the manhattan distance for unit steps.
+pub fn a_star<T, G, C>(
+    grid: &G,
+    starts: impl IntoIterator<Item = Position>,
+    goal: Position,
+    directions: &[Direction],
+    cost: impl FnMut(&T, &T) -> Option<C>,
+    heuristic: impl Fn(Position) -> C,
+) -> Option<(C, Vec<Position>)>
+where
+    G: Grid<T>,
+    C: Copy + Ord + Default + Add<Output = C>,
+{
+    let search = best_first(grid, starts, directions, cost, heuristic, Some(goal));
+    Some((search.distance(goal)?, search.path(goal)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{DenseGrid, GridLoader, Loaded, directions::BASIC_DIRECTIONS};
+
+    const MAZE: &str = "\
+S.#....
+.##.##.
+...#E#.
+.#...#.
+...#...";
+
+    fn maze() -> Loaded<DenseGrid<char>> {
+        GridLoader::new()
+            .with_markers(&['S', 'E'])
+            .load_chars(MAZE)
+            .unwrap()
+    }
+
+    #[test]
+    fn breadth_first() {
+        let maze = maze();
+        let (start, end) = (maze.marker('S').unwrap(), maze.marker('E').unwrap());
+        let search = bfs(&maze.grid, [start], &BASIC_DIRECTIONS, |_, to| *to != '#');
+        assert_eq!(search.distance(end), Some(8));
+        let path = search.path(end).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!((path[0], path[8]), (start, end));
+        assert!(
+            path.windows(2)
+                .all(|step| step[0].manhattan_distance(&step[1]) == 1)
+        );
+        assert_eq!(search.path(Position::new(2, 0)), None);
+        assert_eq!(search.predecessor(start), None);
+
+        // the bottom right corner is closer to the end
+        let search = bfs(
+            &maze.grid,
+            [start, Position::new(6, 4)],
+            &BASIC_DIRECTIONS,
+            |_, to| *to != '#',
+        );
+        assert_eq!(search.distance(end), Some(4));
+        assert_eq!(search.path(end).unwrap()[0], Position::new(6, 4));
+    }
+
+    #[test]
+    fn weighted() {
+        let grid: DenseGrid<u8> = GridLoader::new()
+            .load_chars("1163\n1381\n2136\n3694")
+            .unwrap()
+            .grid;
+        let (start, goal) = (Position::new(0, 0), Position::new(3, 3));
+        let cost = |_: &u8, to: &u8| Some(*to as u32);
+        let search = dijkstra(&grid, [start], &BASIC_DIRECTIONS, cost);
+        assert_eq!(search.distance(goal), Some(17));
+
+        let heuristic = |position: Position| position.manhattan_distance(&goal) as u32;
+        let (total, path) =
+            a_star(&grid, [start], goal, &BASIC_DIRECTIONS, cost, heuristic).unwrap();
+        assert_eq!(total, 17);
+        assert_eq!(
+            path.iter().skip(1).map(|p| grid[*p] as u32).sum::<u32>(),
+            17
+        );
+
+        let walls = |_: &u8, to: &u8| (*to < 9).then_some(*to as u32);
+        let blocked = DenseGrid::from_vec(3, vec![1, 9, 1, 1, 9, 1]);
+        assert_eq!(
+            a_star(
+                &blocked,
+                [start],
+                Position::new(2, 1),
+                &BASIC_DIRECTIONS,
+                walls,
+                |_| 0
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use super::{Grid, boundaries::Boundaries, position::Position};
+
+/// Hash based grid, only the filled cells are stored.
+pub struct SparseGrid<T> {
+    cells: HashMap<Position, T>,
+    pub boundaries: Boundaries,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            boundaries: Boundaries::new(),
+        }
+    }
+
+    pub fn with_boundaries(boundaries: Boundaries) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            boundaries,
+        }
+    }
+
+    pub fn remove(&mut self, position: Position) -> Option<T> {
+        self.cells.remove(&position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+impl<T> Grid<T> for SparseGrid<T> {
+    fn boundaries(&self) -> Boundaries {
+        self.boundaries
+    }
+
+    fn get(&self, position: Position) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    fn set(&mut self, position: Position, value: T) {
+        self.boundaries.update(position.x, position.y);
+        self.cells.insert(position, value);
+    }
+}
+
+impl<T> FromIterator<(Position, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Position, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (position, value) in iter {
+            grid.set(position, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::directions::{ALL_DIRECTIONS, BASIC_DIRECTIONS};
+
+    fn example() -> SparseGrid<char> {
+        let mut grid = SparseGrid::new();
+        for (y, line) in ["#.#", "..#", "##."].iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == '#' {
+                    grid.set(Position::new(x as isize, y as isize), c);
+                }
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn get_set() {
+        let mut grid = example();
+        assert_eq!(grid.len(), 5);
+        assert_eq!(grid.get(Position::new(2, 1)), Some(&'#'));
+        assert!(!grid.contains(Position::new(1, 1)));
+        *grid.get_mut(Position::new(2, 1)).unwrap() = 'x';
+        assert_eq!(grid.get(Position::new(2, 1)), Some(&'x'));
+        grid.set(Position::new(-1, 4), 'o');
+        assert_eq!(grid.boundaries().width(), 4);
+        assert_eq!(grid.boundaries().height(), 5);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = example();
+        let center = Position::new(1, 1);
+        assert_eq!(grid.neighbors(center, &BASIC_DIRECTIONS).count(), 2);
+        assert_eq!(grid.neighbors(center, &ALL_DIRECTIONS).count(), 5);
+    }
+
+    #[test]
+    fn iterate_in_reading_order() {
+        let grid = example();
+        let positions: Vec<Position> = grid.iter().map(|(position, _)| position).collect();
+        assert_eq!(
+            positions,
+            vec![
+                Position::new(0, 0),
+                Position::new(2, 0),
+                Position::new(2, 1),
+                Position::new(0, 2),
+                Position::new(1, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_text() {
+        let grid = example();
+        let text = grid.to_text(|value| value.map_or(".".to_string(), |c| c.to_string()));
+        assert_eq!(text, "#.#\n..#\n##.\n");
+    }
+}
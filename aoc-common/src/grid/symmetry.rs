@@ -0,0 +1,202 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::dense_grid::DenseGrid;
+
+/// The eight symmetries of a rectangle: four rotations, each with or without
+/// a mirror. Rotations are clockwise with y pointing down, like the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    RotateRight,
+    RotateHalf,
+    RotateLeft,
+    /// mirrors every row, left becomes right
+    FlipX,
+    /// mirrors every column, top becomes bottom
+    FlipY,
+    /// mirrors along the diagonal from the top left
+    Transpose,
+    /// mirrors along the diagonal from the top right
+    AntiTranspose,
+}
+
+impl Transform {
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::RotateRight,
+        Transform::RotateHalf,
+        Transform::RotateLeft,
+        Transform::FlipX,
+        Transform::FlipY,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+    ];
+
+    /// true when width and height trade places
+    pub fn swaps_axes(&self) -> bool {
+        matches!(
+            self,
+            Transform::RotateRight
+                | Transform::RotateLeft
+                | Transform::Transpose
+                | Transform::AntiTranspose
+        )
+    }
+
+    /// where the cell at (x, y) of the transformed grid comes from
+    fn source(&self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            Transform::Identity => (x, y),
+            Transform::RotateRight => (y, height - 1 - x),
+            Transform::RotateHalf => (width - 1 - x, height - 1 - y),
+            Transform::RotateLeft => (width - 1 - y, x),
+            Transform::FlipX => (width - 1 - x, y),
+            Transform::FlipY => (x, height - 1 - y),
+            Transform::Transpose => (y, x),
+            Transform::AntiTranspose => (width - 1 - y, height - 1 - x),
+        }
+    }
+}
+
+/// The transforms return new grids with the same origin.
+impl<T: Clone> DenseGrid<T> {
+    pub fn transformed(&self, transform: Transform) -> DenseGrid<T> {
+        let (width, height) = (self.width(), self.height());
+        let new_width = match transform.swaps_axes() {
+            true => height,
+            false => width,
+        };
+        let cells = (0..self.len())
+            .map(|index| {
+                let (x, y) = (index % new_width, index / new_width);
+                let (source_x, source_y) = transform.source(x, y, width, height);
+                self.cells()[source_y * width + source_x].clone()
+            })
+            .collect();
+        DenseGrid::from_vec(new_width, cells).with_origin(self.origin())
+    }
+
+    pub fn rotate_right(&self) -> DenseGrid<T> {
+        self.transformed(Transform::RotateRight)
+    }
+
+    pub fn rotate_left(&self) -> DenseGrid<T> {
+        self.transformed(Transform::RotateLeft)
+    }
+
+    pub fn flip_x(&self) -> DenseGrid<T> {
+        self.transformed(Transform::FlipX)
+    }
+
+    pub fn flip_y(&self) -> DenseGrid<T> {
+        self.transformed(Transform::FlipY)
+    }
+
+    pub fn transpose(&self) -> DenseGrid<T> {
+        self.transformed(Transform::Transpose)
+    }
+
+    /// The distinct rotations and mirrors, the grid itself first. A symmetric
+    /// shape has fewer than eight, a square with one cell only has one.
+    pub fn variants(&self) -> impl Iterator<Item = DenseGrid<T>> + '_
+    where
+        T: PartialEq,
+    {
+        let mut seen: Vec<DenseGrid<T>> = vec![];
+        Transform::ALL.into_iter().filter_map(move |transform| {
+            let variant = self.transformed(transform);
+            if seen.contains(&variant) {
+                return None;
+            }
+            seen.push(variant.clone());
+            Some(variant)
+        })
+    }
+
+    /// The smallest variant, equal for every grid that is a rotation or mirror
+    /// of this one. Narrower grids come first, then the cells are compared.
+    pub fn canonical(&self) -> DenseGrid<T>
+    where
+        T: Ord,
+    {
+        Transform::ALL
+            .into_iter()
+            .map(|transform| self.transformed(transform))
+            .min_by(|a, b| (a.width(), a.cells()).cmp(&(b.width(), b.cells())))
+            .unwrap()
+    }
+
+    /// The same for all rotations and mirrors of the grid, without the need to
+    /// order the cells. The origin is left out.
+    pub fn canonical_hash(&self) -> u64
+    where
+        T: Hash,
+    {
+        Transform::ALL
+            .into_iter()
+            .map(|transform| {
+                let variant = self.transformed(transform);
+                let mut hasher = DefaultHasher::new();
+                (variant.width(), variant.cells()).hash(&mut hasher);
+                hasher.finish()
+            })
+            .min()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{GridLoader, Position};
+
+    fn shape(text: &str) -> DenseGrid<char> {
+        GridLoader::new().load_chars(text).unwrap().grid
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = shape("ab\ncd\nef");
+        assert_eq!(grid.rotate_right(), shape("eca\nfdb"));
+        assert_eq!(grid.rotate_left(), shape("bdf\nace"));
+        assert_eq!(grid.transformed(Transform::RotateHalf), shape("fe\ndc\nba"));
+        assert_eq!(grid.flip_x(), shape("ba\ndc\nfe"));
+        assert_eq!(grid.flip_y(), shape("ef\ncd\nab"));
+        assert_eq!(grid.transpose(), shape("ace\nbdf"));
+        assert_eq!(
+            grid.transformed(Transform::AntiTranspose),
+            shape("fdb\neca")
+        );
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.rotate_right().rotate_right(), grid.flip_x().flip_y());
+
+        let moved = grid.with_origin(Position::new(3, 4));
+        assert_eq!(moved.rotate_right().origin(), Position::new(3, 4));
+    }
+
+    #[test]
+    fn distinct_variants() {
+        assert_eq!(shape("ab\ncd").variants().count(), 8);
+        // an L of three cells only has its four rotations
+        assert_eq!(shape("#.\n##").variants().count(), 4);
+        assert_eq!(shape("##\n##").variants().count(), 1);
+        assert_eq!(shape("###").variants().count(), 2);
+        let variants: Vec<_> = shape("#.\n##").variants().collect();
+        assert_eq!(variants[0], shape("#.\n##"));
+    }
+
+    #[test]
+    fn canonical_form() {
+        let l = shape("#..\n###");
+        let mirrored = shape("###\n#..");
+        let turned = shape("##\n#.\n#.");
+        assert_eq!(l.canonical(), mirrored.canonical());
+        assert_eq!(l.canonical(), turned.canonical());
+        assert_eq!(l.canonical_hash(), turned.canonical_hash());
+        assert_ne!(l.canonical(), shape(".#.\n###").canonical());
+        assert_ne!(l.canonical_hash(), shape(".#.\n###").canonical_hash());
+    }
+}
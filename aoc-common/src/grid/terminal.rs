@@ -0,0 +1,322 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use super::{Grid, boundaries::Boundaries, image::Color, position::Position};
+
+const RESET: &str = "\x1b[0m";
+
+/// How a single cell is drawn: a character with optional 24 bit colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    pub symbol: char,
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub const fn new(symbol: char) -> Self {
+        Style {
+            symbol,
+            foreground: None,
+            background: None,
+            bold: false,
+        }
+    }
+
+    pub const fn with_foreground(mut self, color: Color) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    pub const fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    pub const fn with_bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// the same colors, another symbol
+    pub const fn with_symbol(mut self, symbol: char) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    fn is_plain(&self) -> bool {
+        self.foreground.is_none() && self.background.is_none() && !self.bold
+    }
+
+    fn escape_code(&self) -> String {
+        let mut codes = vec![];
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if let Some(Color { r, g, b }) = self.foreground {
+            codes.push(format!("38;2;{};{};{}", r, g, b));
+        }
+        if let Some(Color { r, g, b }) = self.background {
+            codes.push(format!("48;2;{};{};{}", r, g, b));
+        }
+        format!("\x1b[{}m", codes.join(";"))
+    }
+}
+
+impl From<char> for Style {
+    fn from(symbol: char) -> Self {
+        Style::new(symbol)
+    }
+}
+
+/// Renders grids as text for the terminal. Large maps can be clipped to a
+/// viewport around a focus point and positions like a path can be highlighted
+/// on top of the cells.
+///
+/// ```
+/// use aoc_common::grid::{Color, DenseGrid, Position, Style, TerminalRenderer};
+///
+/// let grid = DenseGrid::from_vec(3, vec!['.', '#', '.', '.', '.', '.']);
+/// let text = TerminalRenderer::new()
+///     .without_colors()
+///     .with_highlight([Position::new(0, 1), Position::new(1, 1)], Style::new('o'))
+///     .render(&grid, |cell| Style::new(*cell.unwrap()));
+/// assert_eq!(text, ".#.\noo.\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TerminalRenderer {
+    viewport: Option<(Position, usize, usize)>,
+    highlights: HashMap<Position, Style>,
+    colors: bool,
+}
+
+impl Default for TerminalRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TerminalRenderer {
+    pub fn new() -> Self {
+        TerminalRenderer {
+            viewport: None,
+            highlights: HashMap::new(),
+            colors: true,
+        }
+    }
+
+    /// Only draws `width` by `height` cells around the focus. Near the edges
+    /// the viewport shifts so it stays within the grid.
+    pub fn with_viewport(mut self, focus: Position, width: usize, height: usize) -> Self {
+        self.viewport = Some((focus, width, height));
+        self
+    }
+
+    /// draws the positions with the style instead of their cell, later highlights win
+    pub fn with_highlight(
+        mut self,
+        positions: impl IntoIterator<Item = Position>,
+        style: impl Into<Style>,
+    ) -> Self {
+        let style = style.into();
+        self.highlights
+            .extend(positions.into_iter().map(|position| (position, style)));
+        self
+    }
+
+    /// only the symbols, for logs and files
+    pub fn without_colors(mut self) -> Self {
+        self.colors = false;
+        self
+    }
+
+    /// the part of the grid that is drawn
+    pub fn visible(&self, boundaries: Boundaries) -> Boundaries {
+        let Some((focus, width, height)) = self.viewport else {
+            return boundaries;
+        };
+        let clip = |focus: isize, size: usize, min: isize, max: isize| {
+            let size = size as isize;
+            let start = (focus - size / 2).min(max - size + 1).max(min);
+            (start, (start + size - 1).min(max))
+        };
+        let (min_x, max_x) = clip(focus.x, width, boundaries.min_x, boundaries.max_x);
+        let (min_y, max_y) = clip(focus.y, height, boundaries.min_y, boundaries.max_y);
+        Boundaries {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        }
+    }
+
+    /// `style` gets None for the empty cells, like `Grid::to_text`
+    pub fn render<T, G: Grid<T> + ?Sized>(
+        &self,
+        grid: &G,
+        style: impl Fn(Option<&T>) -> Style,
+    ) -> String {
+        let visible = self.visible(grid.boundaries());
+        let mut output = String::new();
+        for y in visible.min_y..=visible.max_y {
+            // only switch colors between cells that look different
+            let mut current = Style::new(' ');
+            for x in visible.min_x..=visible.max_x {
+                let position = Position::new(x, y);
+                let cell = match self.highlights.get(&position) {
+                    Some(highlight) => *highlight,
+                    None => style(grid.get(position)),
+                };
+                if self.colors && cell.with_symbol(' ') != current.with_symbol(' ') {
+                    output += RESET;
+                    if !cell.is_plain() {
+                        output += &cell.escape_code();
+                    }
+                    current = cell;
+                }
+                output.push(cell.symbol);
+            }
+            if self.colors && !current.is_plain() {
+                output += RESET;
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    pub fn print<T, G: Grid<T> + ?Sized>(&self, grid: &G, style: impl Fn(Option<&T>) -> Style) {
+        print!("{}", self.render(grid, style));
+    }
+}
+
+/// Redraws frames in place, so a simulation can be followed step by step.
+/// Every frame moves the cursor back up over the previous one.
+pub struct LiveView<W: Write> {
+    writer: W,
+    delay: Duration,
+    lines: usize,
+}
+
+impl LiveView<io::Stdout> {
+    pub fn stdout() -> Self {
+        LiveView::new(io::stdout())
+    }
+}
+
+impl<W: Write> LiveView<W> {
+    pub fn new(writer: W) -> Self {
+        LiveView {
+            writer,
+            delay: Duration::ZERO,
+            lines: 0,
+        }
+    }
+
+    /// the pause after every frame
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn show(&mut self, frame: &str) -> io::Result<()> {
+        if self.lines > 0 {
+            write!(self.writer, "\x1b[{}A", self.lines)?;
+        }
+        let mut lines = 0;
+        for line in frame.lines() {
+            // clears what is left of a longer line of the previous frame
+            writeln!(self.writer, "{}\x1b[K", line)?;
+            lines += 1;
+        }
+        // and the lines below when this frame is shorter
+        write!(self.writer, "\x1b[J")?;
+        self.writer.flush()?;
+        self.lines = lines;
+        if !self.delay.is_zero() {
+            thread::sleep(self.delay);
+        }
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{DenseGrid, SparseGrid};
+
+    fn numbers() -> DenseGrid<u8> {
+        DenseGrid::from_fn(
+            Boundaries {
+                min_x: 0,
+                max_x: 9,
+                min_y: 0,
+                max_y: 4,
+            },
+            |position| (position.x + position.y) as u8 % 10,
+        )
+    }
+
+    fn digit(cell: Option<&u8>) -> Style {
+        Style::new(char::from(b'0' + *cell.unwrap()))
+    }
+
+    #[test]
+    fn clipped_viewport() {
+        let grid = numbers();
+        let text = TerminalRenderer::new()
+            .without_colors()
+            .with_viewport(Position::new(5, 2), 3, 3)
+            .render(&grid, digit);
+        assert_eq!(text, "567\n678\n789\n");
+
+        // near the edge the viewport stays within the grid
+        let renderer = TerminalRenderer::new().with_viewport(Position::new(9, 0), 4, 2);
+        assert_eq!(
+            renderer.visible(grid.boundaries()),
+            Boundaries {
+                min_x: 6,
+                max_x: 9,
+                min_y: 0,
+                max_y: 1,
+            }
+        );
+        let larger = TerminalRenderer::new().with_viewport(Position::new(0, 0), 20, 20);
+        assert_eq!(larger.visible(grid.boundaries()), grid.boundaries());
+    }
+
+    #[test]
+    fn colors_and_highlights() {
+        let mut grid = SparseGrid::new();
+        grid.set(Position::new(0, 0), '#');
+        grid.set(Position::new(2, 0), '#');
+        let red = Style::new('#').with_foreground(Color::RED);
+        let text = TerminalRenderer::new()
+            .with_highlight([Position::new(1, 0)], Style::new('*').with_bold())
+            .render(&grid, |cell| match cell {
+                Some(_) => red,
+                None => Style::new('.'),
+            });
+        assert_eq!(
+            text,
+            "\x1b[0m\x1b[38;2;220;50;47m#\x1b[0m\x1b[1m*\x1b[0m\x1b[38;2;220;50;47m#\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn redraw_in_place() {
+        let mut view = LiveView::new(vec![]);
+        view.show("ab\ncd\n").unwrap();
+        view.show("e\n").unwrap();
+        let output = String::from_utf8(view.into_inner()).unwrap();
+        assert_eq!(output, "ab\x1b[K\ncd\x1b[K\n\x1b[J\x1b[2Ae\x1b[K\n\x1b[J");
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::answers::ANSWERS_FILE;
+
+/// Overrides the directory that is searched first for inputs.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Variant {
+    Real,
+    Example,
+    /// any other input next to the day, e.g. `example2` or `patterns`
+    Extra(String),
+}
+
+impl Variant {
+    /// file extension in the `y2025_day01.example` style layouts
+    pub fn extension(&self) -> &str {
+        match self {
+            Variant::Real => "input",
+            Variant::Example => "example",
+            Variant::Extra(name) => name,
+        }
+    }
+
+    /// file name in the 2022 `day_1/input.example` style layouts
+    pub fn file_name(&self) -> String {
+        match self {
+            Variant::Real => "input".to_string(),
+            Variant::Example => "input.example".to_string(),
+            Variant::Extra(name) => format!("input.{}", name),
+        }
+    }
+}
+
+impl Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Variant::Real => write!(f, "real"),
+            Variant::Example => write!(f, "example"),
+            Variant::Extra(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl FromStr for Variant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("variant can not be empty".to_string()),
+            "real" | "input" => Ok(Variant::Real),
+            "example" => Ok(Variant::Example),
+            name if name.contains(['/', '\\']) => Err(format!("invalid variant {}", name)),
+            name => Ok(Variant::Extra(name.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        year: u16,
+        day: u8,
+        variant: Variant,
+        tried: Vec<PathBuf>,
+    },
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound {
+                year,
+                day,
+                variant,
+                tried,
+            } => {
+                write!(f, "no {} input for {} day {}, tried:", variant, year, day)?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            InputError::Read { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Read { source, .. } => Some(source),
+        }
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The repository root, the closest directory from `start` upwards that holds the answers file.
+pub fn find_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join(ANSWERS_FILE).is_file())
+        .map(Path::to_path_buf)
+}
+
+/// root of the repository the current directory is in, the current directory outside of one
+pub fn workspace_root() -> PathBuf {
+    let current = env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    find_root(&current).unwrap_or(current)
+}
+
+/// Finds the input of a day in every layout the year crates use.
+/// The input directory is searched first, it defaults to `<root>/inputs`.
+#[derive(Debug, Clone)]
+pub struct InputResolver {
+    root: PathBuf,
+    input_dir: PathBuf,
+}
+
+impl InputResolver {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        InputResolver {
+            input_dir: root.join("inputs"),
+            root,
+        }
+    }
+
+    /// resolver for the repository the current directory is in,
+    /// `AOC_INPUT_DIR` replaces the input directory
+    pub fn workspace() -> Self {
+        let resolver = Self::new(workspace_root());
+        match env::var_os(INPUT_DIR_ENV) {
+            Some(dir) if !dir.is_empty() => resolver.with_input_dir(dir),
+            _ => resolver,
+        }
+    }
+
+    pub fn with_input_dir(mut self, input_dir: impl Into<PathBuf>) -> Self {
+        self.input_dir = input_dir.into();
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn input_dir(&self) -> &Path {
+        &self.input_dir
+    }
+
+    /// where an input belongs in the input directory, e.g. `inputs/2025/y2025_day01.input`
+    pub fn input_dir_path(&self, year: u16, day: u8, variant: &Variant) -> PathBuf {
+        self.input_dir.join(year.to_string()).join(format!(
+            "y{}_day{:02}.{}",
+            year,
+            day,
+            variant.extension()
+        ))
+    }
+
+    /// every path that is tried, in order
+    pub fn candidates(&self, year: u16, day: u8, variant: &Variant) -> Vec<PathBuf> {
+        let crate_dir = self.root.join(format!("aoc-{}-rust/src", year));
+        let file_name = variant.file_name();
+        let dated_name = format!("y{}_day{:02}.{}", year, day, variant.extension());
+        vec![
+            self.input_dir_path(year, day, variant),
+            crate_dir.join(format!("days/day_{}", day)).join(&file_name),
+            crate_dir
+                .join(format!("bin/day_{:02}", day))
+                .join(&dated_name),
+            self.root
+                .join(format!("src/{}/data", year))
+                .join(&dated_name),
+        ]
+    }
+
+    pub fn locate(&self, year: u16, day: u8, variant: &Variant) -> Result<PathBuf, InputError> {
+        let tried = self.candidates(year, day, variant);
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(InputError::NotFound {
+                year,
+                day,
+                variant: variant.clone(),
+                tried,
+            }),
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8, variant: &Variant) -> Result<String, InputError> {
+        read_file(&self.locate(year, day, variant)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn parse_variant() {
+        assert_eq!("real".parse(), Ok(Variant::Real));
+        assert_eq!("example".parse(), Ok(Variant::Example));
+        assert_eq!(
+            "example2".parse(),
+            Ok(Variant::Extra("example2".to_string()))
+        );
+        assert!("".parse::<Variant>().is_err());
+        assert!("../input".parse::<Variant>().is_err());
+    }
+
+    #[test]
+    fn locate_repository_layouts() {
+        let resolver = InputResolver::workspace();
+        let located = |year, day, variant: Variant| {
+            let path = resolver.locate(year, day, &variant).unwrap();
+            path.to_string_lossy().replace('\\', "/")
+        };
+        assert!(located(2022, 15, Variant::Example).ends_with("days/day_15/input.example"));
+        assert!(located(2022, 4, Variant::Real).ends_with("days/day_4/input"));
+        assert!(
+            located(2023, 1, Variant::Extra("example2".into())).ends_with("y2023_day01.example2")
+        );
+        assert!(located(2025, 7, Variant::Real).ends_with("bin/day_07/y2025_day07.input"));
+    }
+
+    #[test]
+    fn input_dir_comes_first() {
+        let root = temp_dir("first");
+        let input_dir = root.join("custom");
+        let resolver = InputResolver::new(&root).with_input_dir(&input_dir);
+        let data_dir = root.join("src/2020/data");
+        fs::create_dir_all(&data_dir).unwrap();
+        fs::write(data_dir.join("y2020_day03.input"), "data").unwrap();
+        assert_eq!(resolver.read(2020, 3, &Variant::Real).unwrap(), "data");
+
+        let path = resolver.input_dir_path(2020, 3, &Variant::Real);
+        assert!(path.starts_with(&input_dir));
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "override").unwrap();
+        assert_eq!(resolver.read(2020, 3, &Variant::Real).unwrap(), "override");
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn root_from_a_subdirectory() {
+        let root = temp_dir("root");
+        let nested = root.join("aoc-2020-rust/src");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join(ANSWERS_FILE), "").unwrap();
+        assert_eq!(find_root(&nested), Some(root.clone()));
+        assert_eq!(find_root(&root), Some(root.clone()));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn not_found_lists_every_path() {
+        let root = temp_dir("missing");
+        let resolver = InputResolver::new(&root);
+        let error = resolver.read(2020, 1, &Variant::Example).unwrap_err();
+        let message = error.to_string();
+        assert!(message.starts_with("no example input for 2020 day 1, tried:"));
+        for path in resolver.candidates(2020, 1, &Variant::Example) {
+            assert!(message.contains(&path.display().to_string()));
+        }
+        fs::remove_dir_all(root).unwrap();
+    }
+}
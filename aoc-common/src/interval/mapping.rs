@@ -0,0 +1,294 @@
+use std::ops::{Range, RangeBounds};
+
+use super::{Integer, IntervalSet, half_open};
+
+/// A mapping of integers that moves whole intervals at once: every value of
+/// a source interval shifts by the same offset, values outside all sources map
+/// to themselves. Like the almanac maps of 2023 day 5, which send seeds to soil.
+///
+/// The mappings are kept normalized, so two mappings that send every value to
+/// the same place compare equal.
+///
+/// ```
+/// use aoc_common::interval::{IntervalSet, RangeMap};
+///
+/// let seed_to_soil = RangeMap::new().with_range(98..100, 50).with_range(50..98, 52);
+/// assert_eq!(seed_to_soil.apply(79), 81);
+/// assert_eq!(seed_to_soil.apply(10), 10);
+///
+/// let seeds: IntervalSet<u64> = [79..93].into_iter().collect();
+/// let soil = seed_to_soil.apply_set(&seeds);
+/// assert_eq!(soil.iter().collect::<Vec<_>>(), vec![81..95]);
+/// assert_eq!(seed_to_soil.invert().unwrap().apply(81), 79);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeMap<T> {
+    /// sorted by source, the sources do not overlap
+    pieces: Vec<Piece<T>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Piece<T> {
+    source: Range<T>,
+    /// where the start of the source goes
+    target: T,
+}
+
+impl<T: Integer> Piece<T> {
+    fn map(&self, value: T) -> T {
+        self.target + (value - self.source.start)
+    }
+}
+
+/// Moves `value` by the offset that takes `from` to `to`. The segments that
+/// stay in place can span the whole type, so they are not measured.
+fn shift<T: Integer>(value: T, from: T, to: T) -> T {
+    match from == to {
+        true => value,
+        false => to + (value - from),
+    }
+}
+
+impl<T: Integer> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> RangeMap<T> {
+    /// the identity, every value maps to itself
+    pub fn new() -> Self {
+        RangeMap { pieces: vec![] }
+    }
+
+    /// Maps the source so its first value goes to `target`. Where the source
+    /// overlaps an earlier range the new one wins.
+    pub fn insert(&mut self, source: impl RangeBounds<T>, target: T) {
+        let source = half_open(source);
+        if source.is_empty() {
+            return;
+        }
+        let mut segments: Vec<(Range<T>, T)> = vec![];
+        for piece in self.pieces.iter() {
+            if piece.source.end <= source.start || source.end <= piece.source.start {
+                segments.push((piece.source.clone(), piece.target));
+                continue;
+            }
+            if piece.source.start < source.start {
+                segments.push((piece.source.start..source.start, piece.target));
+            }
+            if source.end < piece.source.end {
+                segments.push((source.end..piece.source.end, piece.map(source.end)));
+            }
+        }
+        segments.push((source, target));
+        *self = RangeMap::from_segments(segments);
+    }
+
+    pub fn with_range(mut self, source: impl RangeBounds<T>, target: T) -> Self {
+        self.insert(source, target);
+        self
+    }
+
+    /// Sorts and merges the segments, the sources may not overlap. Pieces that
+    /// continue the previous one are joined and identities are left out.
+    fn from_segments(mut segments: Vec<(Range<T>, T)>) -> Self {
+        segments.sort_by_key(|(source, _)| source.start);
+        let mut pieces: Vec<Piece<T>> = vec![];
+        for (source, target) in segments {
+            if source.is_empty() || source.start == target {
+                continue;
+            }
+            if let Some(last) = pieces.last_mut()
+                && last.source.end == source.start
+                && last.map(source.start) == target
+            {
+                last.source.end = source.end;
+                continue;
+            }
+            pieces.push(Piece { source, target });
+        }
+        RangeMap { pieces }
+    }
+
+    /// The domain split into intervals that each move by a single offset,
+    /// including the ones that stay in place.
+    fn segments(&self) -> Vec<(Range<T>, T)> {
+        let mut segments = vec![];
+        let mut start = T::MIN;
+        for piece in self.pieces.iter() {
+            if start < piece.source.start {
+                segments.push((start..piece.source.start, start));
+            }
+            segments.push((piece.source.clone(), piece.target));
+            start = piece.source.end;
+        }
+        if start < T::MAX {
+            segments.push((start..T::MAX, start));
+        }
+        segments
+    }
+
+    pub fn apply(&self, value: T) -> T {
+        let index = self
+            .pieces
+            .partition_point(|piece| piece.source.end <= value);
+        match self.pieces.get(index) {
+            Some(piece) if piece.source.start <= value => piece.map(value),
+            _ => value,
+        }
+    }
+
+    /// the image of every value of the set, without going over them one by one
+    pub fn apply_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut image = IntervalSet::new();
+        for (source, target) in self.segments() {
+            let part: IntervalSet<T> = [source.clone()].into_iter().collect();
+            for interval in set.intersection(&part).iter() {
+                image.insert(
+                    shift(interval.start, source.start, target)
+                        ..shift(interval.end, source.start, target),
+                );
+            }
+        }
+        image
+    }
+
+    /// One mapping that does `self` first and `next` after it, like running
+    /// through two stages of the almanac at once.
+    pub fn then(&self, next: &RangeMap<T>) -> RangeMap<T> {
+        let mut segments = vec![];
+        let next_segments = next.segments();
+        for (source, target) in self.segments() {
+            let image =
+                shift(source.start, source.start, target)..shift(source.end, source.start, target);
+            // the parts of the image that the next mapping moves differently
+            let first =
+                next_segments.partition_point(|(next_source, _)| next_source.end <= image.start);
+            for (next_source, next_target) in next_segments[first..].iter() {
+                if next_source.start >= image.end {
+                    break;
+                }
+                let start = image.start.max(next_source.start);
+                let end = image.end.min(next_source.end);
+                segments.push((
+                    shift(start, target, source.start)..shift(end, target, source.start),
+                    shift(start, next_source.start, *next_target),
+                ));
+            }
+        }
+        RangeMap::from_segments(segments)
+    }
+
+    /// The mapping back, None when two values map to the same one.
+    pub fn invert(&self) -> Option<RangeMap<T>> {
+        let mut segments: Vec<(Range<T>, T)> = self
+            .segments()
+            .into_iter()
+            .map(|(source, target)| {
+                let image = shift(source.start, source.start, target)
+                    ..shift(source.end, source.start, target);
+                (image, source.start)
+            })
+            .collect();
+        segments.sort_by_key(|(source, _)| source.start);
+        if segments
+            .windows(2)
+            .any(|pair| pair[0].0.end > pair[1].0.start)
+        {
+            return None;
+        }
+        Some(RangeMap::from_segments(segments))
+    }
+
+    /// every value that maps into the set, also when the mapping can not be inverted
+    pub fn preimage(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut preimage = IntervalSet::new();
+        for (source, target) in self.segments() {
+            let image =
+                shift(source.start, source.start, target)..shift(source.end, source.start, target);
+            let image: IntervalSet<T> = [image].into_iter().collect();
+            for interval in set.intersection(&image).iter() {
+                preimage.insert(
+                    shift(interval.start, target, source.start)
+                        ..shift(interval.end, target, source.start),
+                );
+            }
+        }
+        preimage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_stages() -> Vec<RangeMap<u64>> {
+        // the first three maps of the 2023 day 5 example
+        vec![
+            RangeMap::new()
+                .with_range(98..100, 50)
+                .with_range(50..98, 52),
+            RangeMap::new()
+                .with_range(15..52, 0)
+                .with_range(52..54, 37)
+                .with_range(0..15, 39),
+            RangeMap::new()
+                .with_range(53..61, 49)
+                .with_range(11..53, 0)
+                .with_range(0..7, 42)
+                .with_range(7..11, 57),
+        ]
+    }
+
+    #[test]
+    fn compose_stages() {
+        let stages = example_stages();
+        let combined = stages
+            .iter()
+            .fold(RangeMap::new(), |combined, stage| combined.then(stage));
+        for seed in 0..120 {
+            let step_by_step = stages.iter().fold(seed, |value, stage| stage.apply(value));
+            assert_eq!(combined.apply(seed), step_by_step);
+        }
+        assert_eq!(combined.apply(79), 81);
+        assert_eq!(combined.apply(14), 49);
+
+        let seeds: IntervalSet<u64> = [79..93, 55..68].into_iter().collect();
+        let direct = stages
+            .iter()
+            .fold(seeds.clone(), |set, stage| stage.apply_set(&set));
+        assert_eq!(combined.apply_set(&seeds), direct);
+        assert_eq!(combined.apply_set(&seeds).len(), seeds.len());
+    }
+
+    #[test]
+    fn later_ranges_win() {
+        let map: RangeMap<i32> = RangeMap::new().with_range(0..10, 100).with_range(3..5, -3);
+        assert_eq!(
+            (0..10).map(|value| map.apply(value)).collect::<Vec<_>>(),
+            vec![100, 101, 102, -3, -2, 105, 106, 107, 108, 109]
+        );
+        // moving a range onto itself is the identity
+        assert_eq!(RangeMap::new().with_range(4..8, 4), RangeMap::<i32>::new());
+        let split = RangeMap::new().with_range(0..5, 10).with_range(5..10, 15);
+        assert_eq!(split, RangeMap::new().with_range(0..10, 10));
+    }
+
+    #[test]
+    fn invert() {
+        let swap = RangeMap::new().with_range(0..5, 5).with_range(5..10, 0);
+        let back = swap.invert().unwrap();
+        assert_eq!(swap.then(&back), RangeMap::new());
+        assert_eq!(back.apply(2), 7);
+
+        // 10..15 also lands on itself
+        let squashed: RangeMap<u32> = RangeMap::new().with_range(0..5, 10);
+        assert_eq!(squashed.invert(), None);
+        let targets: IntervalSet<u32> = [12..=13].into_iter().collect();
+        assert_eq!(
+            squashed.preimage(&targets).iter().collect::<Vec<_>>(),
+            vec![2..4, 12..14]
+        );
+    }
+}
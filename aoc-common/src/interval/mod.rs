@@ -0,0 +1,56 @@
+pub mod mapping;
+pub mod set;
+
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, Bound, Range, RangeBounds, Sub},
+};
+
+pub use mapping::RangeMap;
+pub use set::IntervalSet;
+
+/// The primitive integers, so intervals work for ids, coordinates and offsets alike.
+pub trait Integer: Copy + Ord + Hash + Debug + Add<Output = Self> + Sub<Output = Self> {
+    const MIN: Self;
+    const MAX: Self;
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn saturating_add(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn saturating_add(self, other: Self) -> Self {
+                <$t>::saturating_add(self, other)
+            }
+        })*
+    };
+}
+
+impl_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Any range as a half-open one. Unbounded ends go to the limits of the type,
+/// an inclusive end at `T::MAX` leaves that last value out.
+pub fn half_open<T: Integer>(range: impl RangeBounds<T>) -> Range<T> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start.saturating_add(T::ONE),
+        Bound::Unbounded => T::MIN,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end.saturating_add(T::ONE),
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => T::MAX,
+    };
+    start..end
+}
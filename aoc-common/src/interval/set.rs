@@ -0,0 +1,264 @@
+use std::ops::{Range, RangeBounds, RangeInclusive};
+
+use super::{Integer, half_open};
+
+/// A set of integers stored as sorted, disjoint intervals. Touching intervals
+/// are merged, so every set has one representation and equal sets compare equal.
+///
+/// Any kind of range can go in: `3..=5` and `3..6` are the same interval.
+///
+/// ```
+/// use aoc_common::interval::IntervalSet;
+///
+/// let mut fresh: IntervalSet<u64> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+/// assert_eq!(fresh.iter_inclusive().collect::<Vec<_>>(), vec![3..=5, 10..=20]);
+/// assert_eq!(fresh.len(), 14);
+/// assert!(fresh.contains(11) && !fresh.contains(8));
+/// fresh.remove(4..=12);
+/// assert_eq!(fresh.iter().collect::<Vec<_>>(), vec![3..4, 13..21]);
+/// assert_eq!(fresh.gaps().collect::<Vec<_>>(), vec![4..13]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Range<T>>,
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    pub fn insert(&mut self, range: impl RangeBounds<T>) {
+        let Range { mut start, mut end } = half_open(range);
+        if start >= end {
+            return;
+        }
+        // every interval that overlaps or touches gets merged
+        let first = self
+            .intervals
+            .partition_point(|interval| interval.end < start);
+        let last = self
+            .intervals
+            .partition_point(|interval| interval.start <= end);
+        if first < last {
+            start = start.min(self.intervals[first].start);
+            end = end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [start..end]);
+    }
+
+    pub fn remove(&mut self, range: impl RangeBounds<T>) {
+        let Range { start, end } = half_open(range);
+        if start >= end {
+            return;
+        }
+        let first = self
+            .intervals
+            .partition_point(|interval| interval.end <= start);
+        let last = self
+            .intervals
+            .partition_point(|interval| interval.start < end);
+        if first >= last {
+            return;
+        }
+        let before = self.intervals[first].start..start;
+        let after = end..self.intervals[last - 1].end;
+        let kept = [before, after].into_iter().filter(|part| !part.is_empty());
+        self.intervals.splice(first..last, kept);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.start <= value)
+    }
+
+    /// true when every value of the range is in the set, an empty range always is
+    pub fn contains_range(&self, range: impl RangeBounds<T>) -> bool {
+        let Range { start, end } = half_open(range);
+        if start >= end {
+            return true;
+        }
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= start);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.start <= start && end <= interval.end)
+    }
+
+    /// true when the range shares at least one value with the set
+    pub fn overlaps(&self, range: impl RangeBounds<T>) -> bool {
+        let Range { start, end } = half_open(range);
+        if start >= end {
+            return false;
+        }
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= start);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.start < end)
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for interval in other.iter() {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = vec![];
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (left, right) = (&self.intervals[a], &other.intervals[b]);
+            let start = left.start.max(right.start);
+            let end = left.end.min(right.end);
+            if start < end {
+                intervals.push(start..end);
+            }
+            // the interval that ends first can not overlap anything further
+            match left.end < right.end {
+                true => a += 1,
+                false => b += 1,
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = self.clone();
+        for interval in other.iter() {
+            difference.remove(interval);
+        }
+        difference
+    }
+
+    /// the number of values in the set
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::ZERO, |total, interval| {
+            total + (interval.end - interval.start)
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// the smallest and largest value
+    pub fn bounds(&self) -> Option<RangeInclusive<T>> {
+        let first = self.intervals.first()?;
+        let last = self.intervals.last()?;
+        Some(first.start..=last.end - T::ONE)
+    }
+
+    /// the intervals in ascending order, half-open
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.intervals.iter().cloned()
+    }
+
+    /// the intervals in ascending order, with their last value as end
+    pub fn iter_inclusive(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals
+            .iter()
+            .map(|interval| interval.start..=interval.end - T::ONE)
+    }
+
+    /// the missing intervals between the first and last value, half-open
+    pub fn gaps(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.intervals
+            .windows(2)
+            .map(|pair| pair[0].end..pair[1].start)
+    }
+}
+
+impl<T: Integer, R: RangeBounds<T>> FromIterator<R> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl<T: Integer, R: RangeBounds<T>> Extend<R> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_remove() {
+        let mut set = IntervalSet::new();
+        set.insert(10..20);
+        set.insert(30..=39);
+        set.insert(20..25);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![10..25, 30..40]);
+        set.insert(0..100);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..100]);
+        set.remove(..10);
+        set.remove(50..60);
+        set.remove(90..);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![10..50, 60..90]);
+        assert_eq!(set.len(), 70);
+        assert_eq!(set.bounds(), Some(10..=89));
+        set.remove(0..200);
+        assert!(set.is_empty());
+
+        // empty and reversed ranges are ignored
+        set.insert(5..5);
+        #[allow(clippy::reversed_empty_ranges)]
+        set.insert(8..=3);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn queries() {
+        let set: IntervalSet<i32> = [-5..=-1, 4..=8].into_iter().collect();
+        assert!(set.contains(-5) && set.contains(-1) && !set.contains(0));
+        assert!(set.contains_range(5..=8));
+        assert!(!set.contains_range(-2..=4));
+        assert!(set.contains_range(2..2));
+        assert!(set.overlaps(-1..=4));
+        assert!(!set.overlaps(0..4));
+        assert_eq!(set.gaps().collect::<Vec<_>>(), vec![0..4]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: IntervalSet<u32> = [0..10, 20..30].into_iter().collect();
+        let b: IntervalSet<u32> = [5..25, 28..40].into_iter().collect();
+        assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), vec![0..40]);
+        assert_eq!(
+            a.intersection(&b).iter().collect::<Vec<_>>(),
+            vec![5..10, 20..25, 28..30]
+        );
+        assert_eq!(
+            a.difference(&b).iter().collect::<Vec<_>>(),
+            vec![0..5, 25..28]
+        );
+        assert_eq!(
+            b.difference(&a).iter().collect::<Vec<_>>(),
+            vec![10..20, 30..40]
+        );
+        assert_eq!(a.intersection(&IntervalSet::new()), IntervalSet::new());
+    }
+}
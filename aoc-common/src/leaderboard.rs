@@ -0,0 +1,403 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[derive(Debug)]
+pub enum LeaderboardError {
+    Read { path: PathBuf, source: io::Error },
+    Parse(String),
+}
+
+impl Display for LeaderboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaderboardError::Read { path, source } => write!(f, "{}: {}", path.display(), source),
+            LeaderboardError::Parse(message) => write!(f, "invalid leaderboard, {}", message),
+        }
+    }
+}
+
+impl Error for LeaderboardError {}
+
+/// older leaderboards store the timestamps as strings, newer ones as numbers
+fn timestamp<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Timestamp {
+        Number(u64),
+        Text(String),
+    }
+    match Timestamp::deserialize(deserializer)? {
+        Timestamp::Number(ts) => Ok(ts),
+        Timestamp::Text(ts) => ts.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+#[derive(Deserialize)]
+struct RawStar {
+    #[serde(deserialize_with = "timestamp")]
+    get_star_ts: u64,
+}
+
+#[derive(Deserialize)]
+struct RawMember {
+    name: Option<String>,
+    #[serde(default)]
+    local_score: u64,
+    #[serde(default)]
+    completion_day_level: BTreeMap<u8, BTreeMap<u8, RawStar>>,
+}
+
+#[derive(Deserialize)]
+struct RawLeaderboard {
+    event: String,
+    members: HashMap<String, RawMember>,
+}
+
+/// Unix timestamps of the stars of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stars {
+    pub part_1: u64,
+    pub part_2: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: String,
+    pub name: String,
+    /// the score according to the leaderboard itself
+    pub local_score: u64,
+    pub days: BTreeMap<u8, Stars>,
+}
+
+impl Member {
+    pub fn stars(&self) -> usize {
+        self.days
+            .values()
+            .map(|stars| 1 + stars.part_2.is_some() as usize)
+            .sum()
+    }
+
+    pub fn star(&self, day: u8, part: u8) -> Option<u64> {
+        let stars = self.days.get(&day)?;
+        match part {
+            1 => Some(stars.part_1),
+            _ => stars.part_2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Standing {
+    pub id: String,
+    pub name: String,
+    pub stars: usize,
+    pub local_score: u64,
+    /// recomputed from the star timestamps
+    pub score: u64,
+    /// most consecutive days with both stars before the next puzzle unlocked
+    pub streak: u32,
+}
+
+/// One member on one day, times are in seconds since the puzzle unlocked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayResult {
+    pub id: String,
+    pub name: String,
+    pub part_1: u64,
+    pub part_2: Option<u64>,
+    /// seconds between the two stars
+    pub delta: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DaySummary {
+    pub day: u8,
+    pub results: Vec<DayResult>,
+}
+
+/// The standings and the results per day, this is what gets printed as json.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Summary {
+    pub event: u16,
+    pub standings: Vec<Standing>,
+    pub days: Vec<DaySummary>,
+}
+
+impl Summary {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a summary is always valid json")
+    }
+}
+
+/// A private leaderboard as served by `{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: u16,
+    /// ordered by id
+    pub members: Vec<Member>,
+}
+
+/// days that awarded no points because of server outages
+const UNSCORED_DAYS: [(u16, u8); 2] = [(2018, 6), (2020, 1)];
+
+/// days since 1970-01-01, the days from civil algorithm of Howard Hinnant
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, LeaderboardError> {
+        let raw: RawLeaderboard =
+            serde_json::from_str(json).map_err(|e| LeaderboardError::Parse(e.to_string()))?;
+        let event = raw
+            .event
+            .parse()
+            .map_err(|_| LeaderboardError::Parse(format!("unknown event {}", raw.event)))?;
+        let mut members = vec![];
+        for (id, member) in raw.members {
+            let mut days = BTreeMap::new();
+            for (day, stars) in member.completion_day_level {
+                let part_1 = stars.get(&1).ok_or_else(|| {
+                    LeaderboardError::Parse(format!("member {} has day {} without part 1", id, day))
+                })?;
+                let part_2 = stars.get(&2).map(|star| star.get_star_ts);
+                days.insert(
+                    day,
+                    Stars {
+                        part_1: part_1.get_star_ts,
+                        part_2,
+                    },
+                );
+            }
+            members.push(Member {
+                name: member
+                    .name
+                    .unwrap_or_else(|| format!("(anonymous user #{})", id)),
+                id,
+                local_score: member.local_score,
+                days,
+            });
+        }
+        members.sort_by(|a, b| (a.id.len(), &a.id).cmp(&(b.id.len(), &b.id)));
+        Ok(Leaderboard { event, members })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, LeaderboardError> {
+        let json = fs::read_to_string(path).map_err(|source| LeaderboardError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&json)
+    }
+
+    /// puzzles unlock at midnight EST
+    pub fn unlock(&self, day: u8) -> u64 {
+        let days = days_from_civil(self.event as i64, 12, day as i64);
+        (days * 86400 + 5 * 3600) as u64
+    }
+
+    /// every day with at least one star
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .members
+            .iter()
+            .flat_map(|member| member.days.keys().copied())
+            .collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    /// The members with the star in the order they got it.
+    pub fn ranking(&self, day: u8, part: u8) -> Vec<(&Member, u64)> {
+        let mut ranking: Vec<(&Member, u64)> = self
+            .members
+            .iter()
+            .filter_map(|member| Some((member, member.star(day, part)?)))
+            .collect();
+        ranking.sort_by_key(|(_, ts)| *ts);
+        ranking
+    }
+
+    /// The local score of every member by id: the first to get a star
+    /// scores the number of members, the second one less and so on.
+    pub fn scores(&self) -> HashMap<&str, u64> {
+        let mut scores: HashMap<&str, u64> = self
+            .members
+            .iter()
+            .map(|member| (member.id.as_str(), 0))
+            .collect();
+        let days = self.days().into_iter();
+        for day in days.filter(|day| !UNSCORED_DAYS.contains(&(self.event, *day))) {
+            for part in [1, 2] {
+                for (rank, (member, _)) in self.ranking(day, part).into_iter().enumerate() {
+                    *scores.get_mut(member.id.as_str()).unwrap() +=
+                        (self.members.len() - rank) as u64;
+                }
+            }
+        }
+        scores
+    }
+
+    pub fn streak(&self, member: &Member) -> u32 {
+        let (mut longest, mut current) = (0, 0);
+        for day in 1..=25 {
+            let in_time = member
+                .star(day, 2)
+                .is_some_and(|ts| ts < self.unlock(day) + 86400);
+            current = if in_time { current + 1 } else { 0 };
+            longest = longest.max(current);
+        }
+        longest
+    }
+
+    /// Members by recomputed score, most stars first on a tie.
+    pub fn standings(&self) -> Vec<Standing> {
+        let scores = self.scores();
+        let mut standings: Vec<Standing> = self
+            .members
+            .iter()
+            .map(|member| Standing {
+                id: member.id.clone(),
+                name: member.name.clone(),
+                stars: member.stars(),
+                local_score: member.local_score,
+                score: scores[member.id.as_str()],
+                streak: self.streak(member),
+            })
+            .collect();
+        standings.sort_by(|a, b| b.score.cmp(&a.score).then(b.stars.cmp(&a.stars)));
+        standings
+    }
+
+    /// The members with a star on the day, fastest part 1 to part 2 delta first
+    /// and the members without part 2 last.
+    pub fn day_results(&self, day: u8) -> Vec<DayResult> {
+        let unlock = self.unlock(day);
+        let mut results: Vec<DayResult> = self
+            .ranking(day, 1)
+            .into_iter()
+            .map(|(member, part_1)| {
+                let part_2 = member.star(day, 2);
+                DayResult {
+                    id: member.id.clone(),
+                    name: member.name.clone(),
+                    part_1: part_1.saturating_sub(unlock),
+                    part_2: part_2.map(|ts| ts.saturating_sub(unlock)),
+                    delta: part_2.map(|ts| ts.saturating_sub(part_1)),
+                }
+            })
+            .collect();
+        results.sort_by_key(|result| result.delta.unwrap_or(u64::MAX));
+        results
+    }
+
+    /// every day with a star, or only `day`
+    pub fn summary(&self, day: Option<u8>) -> Summary {
+        let days = self
+            .days()
+            .into_iter()
+            .filter(|d| day.is_none_or(|day| day == *d))
+            .map(|day| DaySummary {
+                day,
+                results: self.day_results(day),
+            })
+            .collect();
+        Summary {
+            event: self.event,
+            standings: self.standings(),
+            days,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEADERBOARD: &str = include_str!("../../src/2020/data/leaderboard.json");
+
+    #[test]
+    fn parse_leaderboard() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+        assert_eq!(leaderboard.event, 2020);
+        assert_eq!(leaderboard.members.len(), 6);
+        assert_eq!(leaderboard.members[0].id, "910810");
+        let hammerlink = &leaderboard.members[1];
+        assert_eq!(hammerlink.name, "hammerlink");
+        assert_eq!(hammerlink.stars(), 26);
+        assert_eq!(hammerlink.star(1, 2), Some(1606799783));
+
+        let newer = r#"{"event":"2024","owner_id":1,"members":{"7":{"id":7,"name":null,"stars":1,
+            "local_score":1,"last_star_ts":1733029000,
+            "completion_day_level":{"1":{"1":{"get_star_ts":1733029000,"star_index":0}}}}}}"#;
+        let leaderboard = Leaderboard::parse(newer).unwrap();
+        assert_eq!(leaderboard.members[0].name, "(anonymous user #7)");
+        assert_eq!(leaderboard.members[0].days[&1].part_2, None);
+        assert!(Leaderboard::parse(r#"{"event":"x","members":{}}"#).is_err());
+    }
+
+    #[test]
+    fn unlock_time() {
+        let leaderboard = Leaderboard {
+            event: 2020,
+            members: vec![],
+        };
+        // 2020-12-01T05:00:00Z
+        assert_eq!(leaderboard.unlock(1), 1606798800);
+        assert_eq!(leaderboard.unlock(25), 1606798800 + 24 * 86400);
+    }
+
+    #[test]
+    fn recomputed_scores() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+        for standing in leaderboard.standings() {
+            assert_eq!(standing.score, standing.local_score, "{}", standing.name);
+        }
+        let standings = leaderboard.standings();
+        assert_eq!(standings[0].name, "hammerlink");
+        assert_eq!(standings[5].stars, 0);
+    }
+
+    #[test]
+    fn deltas_and_streaks() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+        let results = leaderboard.day_results(13);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].name, "Willem");
+        assert_eq!(results[0].delta, Some(1607843692 - 1607837398));
+        assert_eq!(results[2].delta, None);
+
+        let streak = |name| {
+            let member = leaderboard.members.iter().find(|m| m.name == name).unwrap();
+            leaderboard.streak(member)
+        };
+        assert_eq!(streak("hammerlink"), 13);
+        assert_eq!(streak("fredericwatteyne"), 0);
+    }
+
+    #[test]
+    fn summary_json() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+        let summary = leaderboard.summary(Some(13));
+        assert_eq!(summary.days.len(), 1);
+        let json: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+        assert_eq!(json["standings"][0]["score"], 132);
+        assert_eq!(
+            json["days"][0]["results"][2]["part_2"],
+            serde_json::Value::Null
+        );
+    }
+}
@@ -0,0 +1,13 @@
+pub mod answers;
+pub mod bench;
+pub mod cover;
+pub mod download;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod leaderboard;
+pub mod registry;
+pub mod solution;
+pub mod submit;
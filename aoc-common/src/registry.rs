@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+
+use crate::solution::{Puzzle, Solution};
+
+/// All known puzzles keyed by (year, day). Every year crate exposes a
+/// `register(&mut Registry)` function which adds its days.
+#[derive(Default)]
+pub struct Registry {
+    puzzles: BTreeMap<(u16, u8), Box<dyn Puzzle>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry {
+            puzzles: BTreeMap::new(),
+        }
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self, solution: S) {
+        let key = (S::YEAR, S::DAY);
+        if self.puzzles.contains_key(&key) {
+            panic!("{} day {} is registered twice", key.0, key.1);
+        }
+        self.puzzles.insert(key, Box::new(solution));
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn Puzzle> {
+        self.puzzles.get(&(year, day)).map(|p| p.as_ref())
+    }
+
+    pub fn contains(&self, year: u16, day: u8) -> bool {
+        self.puzzles.contains_key(&(year, day))
+    }
+
+    /// Ordered by year, then day.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Puzzle> {
+        self.puzzles.values().map(|p| p.as_ref())
+    }
+
+    pub fn iter_year(&self, year: u16) -> impl Iterator<Item = &dyn Puzzle> {
+        self.puzzles
+            .range((year, 0)..=(year, u8::MAX))
+            .map(|(_, p)| p.as_ref())
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.puzzles.keys().map(|(year, _)| *year).collect();
+        years.dedup();
+        years
+    }
+
+    pub fn len(&self) -> usize {
+        self.puzzles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.puzzles.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        input::Variant,
+        solution::{Answer, Part},
+    };
+
+    struct Day<const YEAR: u16, const DAY: u8>;
+    impl<const YEAR: u16, const DAY: u8> Solution for Day<YEAR, DAY> {
+        const YEAR: u16 = YEAR;
+        const DAY: u8 = DAY;
+
+        type Input<'a> = &'a str;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+            input
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+            input.len()
+        }
+
+        fn part_2(&self, _input: &Self::Input<'_>) -> Self::Part2 {
+            DAY as usize
+        }
+    }
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry.register(Day::<2025, 2>);
+        registry.register(Day::<2022, 16>);
+        registry.register(Day::<2025, 1>);
+        registry
+    }
+
+    #[test]
+    fn ordered_iteration() {
+        let registry = registry();
+        let keys: Vec<(u16, u8)> = registry.iter().map(|p| (p.year(), p.day())).collect();
+        assert_eq!(keys, vec![(2022, 16), (2025, 1), (2025, 2)]);
+        assert_eq!(registry.years(), vec![2022, 2025]);
+        assert_eq!(registry.iter_year(2025).count(), 2);
+    }
+
+    #[test]
+    fn lookup() {
+        let registry = registry();
+        let puzzle = registry.get(2022, 16).unwrap();
+        let run = puzzle.run("abc", &Variant::Real, &Part::ALL);
+        assert_eq!(run.answer(Part::One), Some(&Answer::Number(3)));
+        assert_eq!(run.answer(Part::Two), Some(&Answer::Number(16)));
+        assert!(registry.get(2023, 1).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn duplicate_registration() {
+        let mut registry = registry();
+        registry.register(Day::<2025, 1>);
+    }
+}
@@ -0,0 +1,262 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use crate::input::Variant;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// the part has not been solved (yet)
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+/// values beyond `i128` are kept as text, so they still print as the number
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::Unsolved
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn new(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A single puzzle day. Parsing happens once, both parts work on the parsed input.
+/// Use `()` as answer type for a part that is not solved.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input<'a>;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Input<'a>;
+    /// Override when an example asks for other parameters than the real puzzle,
+    /// e.g. a smaller search area. The parameters then travel in the parsed input.
+    fn parse_variant<'a>(&self, input: &'a str, _variant: &Variant) -> Self::Input<'a> {
+        self.parse(input)
+    }
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1;
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2;
+}
+
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+pub struct Run {
+    pub parse_duration: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+impl Run {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.parts.iter().find(|p| p.part == part).map(|p| &p.answer)
+    }
+}
+
+/// Object safe view on a `Solution`, this is what gets stored in the registry.
+pub trait Puzzle {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn run(&self, input: &str, variant: &Variant, parts: &[Part]) -> Run;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, variant: &Variant, parts: &[Part]) -> Run {
+        let start = Instant::now();
+        let parsed = self.parse_variant(input, variant);
+        let parse_duration = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => self.part_1(&parsed).into(),
+                    Part::Two => self.part_2(&parsed).into(),
+                };
+                PartRun {
+                    part: *part,
+                    answer,
+                    duration: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Run {
+            parse_duration,
+            parts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Input<'a> = Vec<i64>;
+        type Part1 = i64;
+        type Part2 = ();
+
+        fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+            input.iter().sum()
+        }
+
+        fn part_2(&self, _input: &Self::Input<'_>) -> Self::Part2 {}
+    }
+
+    /// sums only the first two numbers of the example
+    struct Head;
+    impl Solution for Head {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 2;
+
+        type Input<'a> = Vec<i64>;
+        type Part1 = i64;
+        type Part2 = ();
+
+        fn parse<'a>(&self, input: &'a str) -> Self::Input<'a> {
+            Sum.parse(input)
+        }
+
+        fn parse_variant<'a>(&self, input: &'a str, variant: &Variant) -> Self::Input<'a> {
+            let mut numbers = self.parse(input);
+            if *variant == Variant::Example {
+                numbers.truncate(2);
+            }
+            numbers
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+            input.iter().sum()
+        }
+
+        fn part_2(&self, _input: &Self::Input<'_>) -> Self::Part2 {}
+    }
+
+    #[test]
+    fn run_both_parts() {
+        let run = Sum.run("1\n2\n-4", &Variant::Real, &Part::ALL);
+        assert_eq!(run.answer(Part::One), Some(&Answer::Number(-1)));
+        assert_eq!(run.answer(Part::Two), Some(&Answer::Unsolved));
+    }
+
+    #[test]
+    fn run_single_part() {
+        let run = Sum.run("5", &Variant::Real, &[Part::Two]);
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.answer(Part::One), None);
+    }
+
+    #[test]
+    fn run_with_variant() {
+        let run = Head.run("1\n2\n3", &Variant::Example, &[Part::One]);
+        assert_eq!(run.answer(Part::One), Some(&Answer::Number(3)));
+        let run = Head.run("1\n2\n3", &Variant::Real, &[Part::One]);
+        assert_eq!(run.answer(Part::One), Some(&Answer::Number(6)));
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::from(()).to_string(), "-");
+        assert_eq!(Answer::from(7_u128), Answer::Number(7));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+}
@@ -0,0 +1,462 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    download::{BASE_URL, Http, HttpError},
+    solution::{Answer, Part},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without a hint about the direction
+    Wrong,
+    /// an answer was given too recently, nothing was checked
+    Wait(Option<Duration>),
+    /// the part is already solved or not unlocked yet
+    WrongLevel,
+}
+
+impl Verdict {
+    /// reads the verdict from the html the site answers with
+    pub fn classify(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if response.contains("answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if response.contains("answer is too low") {
+            Some(Verdict::TooLow)
+        } else if response.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if response.contains("You gave an answer too recently") {
+            Some(Verdict::Wait(parse_wait(response)))
+        } else if response.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// false when the answer was not looked at
+    pub fn is_judged(&self) -> bool {
+        !matches!(self, Verdict::Wait(_) | Verdict::WrongLevel)
+    }
+}
+
+/// "You have 1m 5s left to wait." -> 65s
+fn parse_wait(response: &str) -> Option<Duration> {
+    let start = response.find("You have ")? + "You have ".len();
+    let end = start + response[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for token in response[start..end].split_whitespace() {
+        let (value, unit) = token.split_at(token.len() - 1);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(Some(duration)) => write!(f, "wait {}s", duration.as_secs()),
+            Verdict::Wait(None) => write!(f, "wait"),
+            Verdict::WrongLevel => write!(f, "wrong level"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "wait" => Ok(Verdict::Wait(None)),
+            "wrong level" => Ok(Verdict::WrongLevel),
+            wait => wait
+                .strip_prefix("wait ")
+                .and_then(|s| s.strip_suffix('s'))
+                .and_then(|s| s.parse().ok())
+                .map(|seconds| Verdict::Wait(Some(Duration::from_secs(seconds))))
+                .ok_or_else(|| format!("unknown verdict {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// unix seconds
+    pub time: u64,
+}
+
+impl Display for Guess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.year, self.day, self.part, self.answer, self.verdict, self.time
+        )
+    }
+}
+
+impl FromStr for Guess {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split('\t').collect();
+        let [year, day, part, answer, verdict, time] = fields[..] else {
+            return Err(format!("invalid guess line {}", s));
+        };
+        let number = |field: &str| {
+            field
+                .parse::<u64>()
+                .map_err(|e| format!("{}: {}", field, e))
+        };
+        Ok(Guess {
+            year: number(year)? as u16,
+            day: number(day)? as u8,
+            part: Part::new(number(part)? as u8).ok_or(format!("invalid part {}", part))?,
+            answer: answer.to_string(),
+            verdict: verdict.parse()?,
+            time: number(time)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// nothing to submit
+    Unsolved,
+    AlreadySolved(String),
+    AlreadyGuessed(Verdict),
+    /// at or above an answer that was too high
+    AboveTooHigh(String),
+    /// at or below an answer that was too low
+    BelowTooLow(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Unsolved => write!(f, "the part has no answer"),
+            Refusal::AlreadySolved(answer) => write!(f, "already solved with {}", answer),
+            Refusal::AlreadyGuessed(verdict) => write!(f, "already guessed, it was {}", verdict),
+            Refusal::AboveTooHigh(guess) => write!(f, "{} was already too high", guess),
+            Refusal::BelowTooLow(guess) => write!(f, "{} was already too low", guess),
+        }
+    }
+}
+
+/// Every answer that was submitted, stored as tab separated lines.
+pub struct GuessHistory {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl GuessHistory {
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let guesses = match fs::read_to_string(&path) {
+            Ok(content) => content
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(|line| line.parse().map_err(io::Error::other))
+                .collect::<io::Result<_>>()?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e),
+        };
+        Ok(GuessHistory { path, guesses })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn guesses(&self, year: u16, day: u8, part: Part) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |g| g.year == year && g.day == day && g.part == part)
+    }
+
+    /// why the answer should not be submitted, based on the earlier guesses
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<(), Refusal> {
+        if *answer == Answer::Unsolved {
+            return Err(Refusal::Unsolved);
+        }
+        let text = answer.to_string();
+        let judged = || {
+            self.guesses(year, day, part)
+                .filter(|g| g.verdict.is_judged())
+        };
+        if let Some(correct) = judged().find(|g| g.verdict == Verdict::Correct) {
+            return Err(Refusal::AlreadySolved(correct.answer.clone()));
+        }
+        if let Some(guess) = judged().find(|g| g.answer == text) {
+            return Err(Refusal::AlreadyGuessed(guess.verdict.clone()));
+        }
+        if let Answer::Number(value) = answer {
+            let bound = |verdict: Verdict| {
+                judged()
+                    .filter(move |g| g.verdict == verdict)
+                    .filter_map(|g| Some((g.answer.parse::<i128>().ok()?, &g.answer)))
+            };
+            if let Some((_, guess)) = bound(Verdict::TooHigh)
+                .filter(|(high, _)| value >= high)
+                .min()
+            {
+                return Err(Refusal::AboveTooHigh(guess.clone()));
+            }
+            if let Some((_, guess)) = bound(Verdict::TooLow).filter(|(low, _)| value <= low).max() {
+                return Err(Refusal::BelowTooLow(guess.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, guess: Guess) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", guess)?;
+        self.guesses.push(guess);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Http(HttpError),
+    /// the response did not contain a known message
+    UnknownResponse(String),
+    History(io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitted, {}", refusal),
+            SubmitError::Http(error) => write!(f, "submit failed, {}", error),
+            SubmitError::UnknownResponse(body) => write!(f, "unknown response: {}", body),
+            SubmitError::History(error) => write!(f, "guess history: {}", error),
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+/// Submits answers, every judged or throttled answer is recorded in the history.
+pub struct Submitter<H> {
+    http: H,
+    session: String,
+    base_url: String,
+    history: GuessHistory,
+}
+
+impl<H: Http> Submitter<H> {
+    pub fn new(http: H, session: impl Into<String>, history: GuessHistory) -> Self {
+        Submitter {
+            http,
+            session: session.into(),
+            base_url: BASE_URL.to_string(),
+            history,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn history(&self) -> &GuessHistory {
+        &self.history
+    }
+
+    pub fn submit(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<Verdict, SubmitError> {
+        self.history
+            .check(year, day, part, answer)
+            .map_err(SubmitError::Refused)?;
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        let text = answer.to_string();
+        let response = self
+            .http
+            .post(&url, &self.session, &[("level", &level), ("answer", &text)])
+            .map_err(SubmitError::Http)?;
+        let verdict = Verdict::classify(&response).ok_or(SubmitError::UnknownResponse(response))?;
+
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.history
+            .record(Guess {
+                year,
+                day,
+                part,
+                answer: text,
+                verdict: verdict.clone(),
+                time,
+            })
+            .map_err(SubmitError::History)?;
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, env};
+
+    use super::*;
+
+    const TOO_HIGH: &str = "<p>That's not the right answer; your answer is too high.</p>";
+    const TOO_LOW: &str = "<p>That's not the right answer; your answer is too low.</p>";
+    const CORRECT: &str = "<p>That's the right answer! You are one gold star closer.</p>";
+    const WAIT: &str = "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p>";
+
+    struct FakeHttp {
+        responses: RefCell<Vec<&'static str>>,
+        forms: RefCell<Vec<String>>,
+    }
+
+    impl FakeHttp {
+        fn new(responses: Vec<&'static str>) -> Self {
+            FakeHttp {
+                responses: RefCell::new(responses),
+                forms: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    impl Http for FakeHttp {
+        fn get(&self, _: &str, _: &str) -> Result<String, HttpError> {
+            unreachable!("answers are only posted")
+        }
+
+        fn post(&self, url: &str, _: &str, form: &[(&str, &str)]) -> Result<String, HttpError> {
+            let form: Vec<String> = form.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+            self.forms
+                .borrow_mut()
+                .push(format!("{} {}", url, form.join("&")));
+            Ok(self.responses.borrow_mut().remove(0).to_string())
+        }
+    }
+
+    fn history(name: &str) -> GuessHistory {
+        let path = env::temp_dir().join(format!("aoc-guesses-{}-{}.tsv", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        GuessHistory::open(path).unwrap()
+    }
+
+    #[test]
+    fn classify_responses() {
+        assert_eq!(Verdict::classify(TOO_HIGH), Some(Verdict::TooHigh));
+        assert_eq!(Verdict::classify(TOO_LOW), Some(Verdict::TooLow));
+        assert_eq!(Verdict::classify(CORRECT), Some(Verdict::Correct));
+        assert_eq!(
+            Verdict::classify(WAIT),
+            Some(Verdict::Wait(Some(Duration::from_secs(65))))
+        );
+        assert_eq!(Verdict::classify("<html></html>"), None);
+        for verdict in [Verdict::TooLow, Verdict::Wait(Some(Duration::from_secs(9)))] {
+            assert_eq!(verdict.to_string().parse(), Ok(verdict));
+        }
+    }
+
+    #[test]
+    fn refuse_known_guesses() {
+        let http = FakeHttp::new(vec![TOO_HIGH, TOO_LOW, WAIT, CORRECT]);
+        let mut submitter =
+            Submitter::new(&http, "session", history("refuse")).with_base_url("http://aoc");
+        let submit = |submitter: &mut Submitter<&FakeHttp>, answer: i64| {
+            submitter.submit(2022, 15, Part::One, &Answer::from(answer))
+        };
+
+        assert_eq!(submit(&mut submitter, 100).unwrap(), Verdict::TooHigh);
+        assert!(matches!(
+            submit(&mut submitter, 150),
+            Err(SubmitError::Refused(Refusal::AboveTooHigh(high))) if high == "100"
+        ));
+        assert_eq!(submit(&mut submitter, 10).unwrap(), Verdict::TooLow);
+        assert!(matches!(
+            submit(&mut submitter, 10),
+            Err(SubmitError::Refused(Refusal::AlreadyGuessed(
+                Verdict::TooLow
+            )))
+        ));
+        // a throttled guess was never judged, it can be sent again
+        assert!(matches!(
+            submit(&mut submitter, 50).unwrap(),
+            Verdict::Wait(_)
+        ));
+        assert_eq!(submit(&mut submitter, 50).unwrap(), Verdict::Correct);
+        assert!(matches!(
+            submit(&mut submitter, 51),
+            Err(SubmitError::Refused(Refusal::AlreadySolved(answer))) if answer == "50"
+        ));
+        assert!(matches!(
+            submitter.submit(2022, 15, Part::Two, &Answer::Unsolved),
+            Err(SubmitError::Refused(Refusal::Unsolved))
+        ));
+        assert_eq!(
+            http.forms.borrow()[0],
+            "http://aoc/2022/day/15/answer level=1&answer=100"
+        );
+        assert_eq!(http.forms.borrow().len(), 4);
+    }
+
+    #[test]
+    fn history_is_persisted() {
+        let http = FakeHttp::new(vec![TOO_LOW]);
+        let mut submitter = Submitter::new(&http, "session", history("persist"));
+        submitter
+            .submit(2023, 1, Part::Two, &Answer::from("abc"))
+            .unwrap();
+
+        let reopened = GuessHistory::open(submitter.history().path()).unwrap();
+        let guesses: Vec<&Guess> = reopened.guesses(2023, 1, Part::Two).collect();
+        assert_eq!(guesses.len(), 1);
+        assert_eq!(guesses[0].answer, "abc");
+        assert_eq!(guesses[0].verdict, Verdict::TooLow);
+        assert_eq!(reopened.guesses(2023, 1, Part::One).count(), 0);
+        fs::remove_file(reopened.path()).unwrap();
+    }
+}